@@ -0,0 +1,90 @@
+//! Snapshots of the custom shortcuts, taken before they are reset.
+
+use super::export::ShortcutsFile;
+use cosmic_settings_config::shortcuts::Shortcuts;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const PREFIX: &str = "custom-";
+/// Prefix of custom shortcuts configs which could not be read.
+const CORRUPT_PREFIX: &str = "corrupt-";
+const EXTENSION: &str = ".ron";
+/// Number of backups which are kept, after which the oldest are removed.
+const KEEP: usize = 10;
+
+/// Directory where backups of the custom shortcuts are stored.
+fn dir() -> io::Result<PathBuf> {
+    dirs::data_local_dir()
+        .map(|dir| dir.join("cosmic-settings").join("shortcut-backups"))
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no local data directory"))
+}
+
+/// Writes a timestamped backup of the custom shortcuts, removing the oldest beyond [`KEEP`].
+pub fn create(custom: Shortcuts) -> io::Result<PathBuf> {
+    let dir = dir()?;
+    std::fs::create_dir_all(&dir)?;
+
+    let contents = ShortcutsFile::new(custom)
+        .to_ron()
+        .map_err(io::Error::other)?;
+
+    let path = dir.join(format!("{PREFIX}{}{EXTENSION}", timestamp()));
+    std::fs::write(&path, contents)?;
+
+    let backups = list(&dir)?;
+    for old in &backups[..backups.len().saturating_sub(KEEP)] {
+        if let Err(why) = std::fs::remove_file(old) {
+            tracing::warn!(?why, ?old, "failed to remove an old shortcuts backup");
+        }
+    }
+
+    Ok(path)
+}
+
+/// Moves an unreadable custom shortcuts config into the backup directory.
+pub fn set_aside(config: &Path) -> io::Result<PathBuf> {
+    let dir = dir()?;
+    std::fs::create_dir_all(&dir)?;
+
+    let path = dir.join(format!("{CORRUPT_PREFIX}{}{EXTENSION}", timestamp()));
+
+    // The config and data directories may be on different filesystems.
+    std::fs::copy(config, &path)?;
+    std::fs::remove_file(config)?;
+
+    Ok(path)
+}
+
+fn timestamp() -> impl std::fmt::Display {
+    chrono::Local::now().format("%Y%m%d-%H%M%S%.3f")
+}
+
+/// Backups of the custom shortcuts in the directory, from oldest to newest.
+///
+/// Unreadable configs which were set aside are not included.
+fn list(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut backups = std::fs::read_dir(dir)?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(PREFIX) && name.ends_with(EXTENSION))
+        })
+        .collect::<Vec<_>>();
+
+    // Timestamps are zero-padded, so the newest backup sorts last.
+    backups.sort();
+    Ok(backups)
+}
+
+/// Path of the most recent backup, if one exists.
+pub fn latest() -> Option<PathBuf> {
+    list(&dir().ok()?).ok()?.pop()
+}
+
+/// Reads the custom shortcuts stored in a backup.
+pub fn read(path: &Path) -> Result<Shortcuts, String> {
+    let contents = std::fs::read_to_string(path).map_err(|why| why.to_string())?;
+    ShortcutsFile::from_ron(&contents).map(|file| file.custom)
+}
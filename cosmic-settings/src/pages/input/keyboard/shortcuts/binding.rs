@@ -0,0 +1,190 @@
+//! Parsing of bindings typed by the user, in the various styles they are written in.
+
+use cosmic_settings_config::Binding;
+use std::str::FromStr;
+
+/// Alternative names of modifiers, and the modifier which they are written as in a binding.
+const MODIFIER_ALIASES: &[(&[&str], &str)] = &[
+    (
+        &[
+            "super", "logo", "mod4", "win", "windows", "cmd", "command", "meta",
+        ],
+        "Super",
+    ),
+    (&["ctrl", "control", "ctl", "primary"], "Ctrl"),
+    (&["alt", "mod1", "option", "opt"], "Alt"),
+    (&["shift"], "Shift"),
+];
+
+/// Keysyms of either side's modifier keys, and the modifier which they are bound as.
+const MODIFIER_KEYSYMS: &[(&[&str], &str)] = &[
+    (&["Super_L", "Super_R", "Meta_L", "Meta_R"], "Super"),
+    (&["Control_L", "Control_R"], "Ctrl"),
+    (&["Alt_L", "Alt_R"], "Alt"),
+    (&["Shift_L", "Shift_R"], "Shift"),
+];
+
+/// Parses a binding, accepting `+`, `-`, and spaces as separators, and common modifier aliases.
+pub fn parse(input: &str) -> Result<Binding, <Binding as FromStr>::Err> {
+    Binding::from_str(&normalize(input).unwrap_or_else(|| input.to_owned()))
+}
+
+/// Rewrites a binding in its canonical form, so that a key combination is always stored alike.
+///
+/// A binding whose key is itself a modifier, as is captured when only modifiers are pressed,
+/// is bound as that modifier. Which side's key was pressed, and the order the modifiers were
+/// pressed in, then make no difference.
+pub fn canonical(binding: &Binding) -> Binding {
+    let text = binding.to_string();
+    let mut words = text.split('+').collect::<Vec<_>>();
+
+    let modifier = words.last().and_then(|key| {
+        MODIFIER_KEYSYMS
+            .iter()
+            .find(|(keysyms, _)| keysyms.contains(key))
+            .map(|(_, modifier)| *modifier)
+    });
+
+    if let Some(modifier) = modifier {
+        words.pop();
+        if !words.contains(&modifier) {
+            words.push(modifier);
+        }
+    }
+
+    match parse(&words.join("+")) {
+        Ok(mut canonical) => {
+            canonical.description.clone_from(&binding.description);
+            canonical
+        }
+        Err(_) => binding.clone(),
+    }
+}
+
+/// Rewrites a binding in the form expected by `Binding::from_str`, if it can be read.
+fn normalize(input: &str) -> Option<String> {
+    let input = input.trim();
+
+    // A separator at the end of the input is the key itself, such as in `Ctrl++`.
+    let (rest, key) = match input.char_indices().next_back()? {
+        (pos, c @ ('+' | '-')) if pos == 0 || input[..pos].ends_with(is_separator) => {
+            (&input[..pos], Some(if c == '+' { "plus" } else { "minus" }))
+        }
+        _ => (input, None),
+    };
+
+    let mut words = rest.split(is_separator).filter(|word| !word.is_empty());
+
+    let key = match key {
+        Some(key) => key.to_owned(),
+        None => {
+            let key = words.next_back()?;
+            modifier(key).map_or_else(|| key_name(key), str::to_owned)
+        }
+    };
+
+    let mut binding = words
+        .map(|word| modifier(word).map(|modifier| modifier.to_owned() + "+"))
+        .collect::<Option<String>>()?;
+
+    binding.push_str(&key);
+    Some(binding)
+}
+
+fn is_separator(c: char) -> bool {
+    c == '+' || c == '-' || c.is_whitespace()
+}
+
+fn modifier(word: &str) -> Option<&'static str> {
+    let word = word.to_ascii_lowercase();
+
+    MODIFIER_ALIASES
+        .iter()
+        .find(|(aliases, _)| aliases.contains(&word.as_str()))
+        .map(|(_, modifier)| *modifier)
+}
+
+/// Letters are written in lowercase, since uppercase letters are distinct keysyms.
+fn key_name(key: &str) -> String {
+    let mut chars = key.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_alphabetic() => c.to_ascii_lowercase().to_string(),
+        _ => key.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmic_settings_config::shortcuts::Modifiers;
+
+    #[test]
+    fn accepted_styles() {
+        assert_eq!(normalize("Ctrl+Shift+A").as_deref(), Some("Ctrl+Shift+a"));
+        assert_eq!(normalize("Cmd-A").as_deref(), Some("Super+a"));
+        assert_eq!(normalize("Super A").as_deref(), Some("Super+a"));
+        assert_eq!(normalize(" win + t ").as_deref(), Some("Super+t"));
+        assert_eq!(
+            normalize("Primary+Mod1+Delete").as_deref(),
+            Some("Ctrl+Alt+Delete")
+        );
+        assert_eq!(normalize("Ctrl++").as_deref(), Some("Ctrl+plus"));
+        assert_eq!(normalize("Ctrl+-").as_deref(), Some("Ctrl+minus"));
+        assert_eq!(normalize("logo").as_deref(), Some("Super"));
+        assert_eq!(parse("Cmd-A").ok(), Binding::from_str("Super+a").ok());
+    }
+
+    #[test]
+    fn menu_key() {
+        let binding = parse("Super+Menu").unwrap();
+        assert_eq!(binding.to_string(), "Super+Menu");
+        assert_eq!(parse(&binding.to_string()).ok(), Some(binding));
+    }
+
+    #[test]
+    fn canonical_modifier_keys() {
+        // Pressing Shift and then the right logo key, or the left logo key and then Shift.
+        let mut logo_last = Binding::from_str("Super_R").unwrap();
+        logo_last.modifiers.shift = true;
+        let mut shift_last = Binding::from_str("Shift_L").unwrap();
+        shift_last.modifiers.logo = true;
+
+        let mut expected = Binding::new(Modifiers::new().logo(), None);
+        expected.modifiers.shift = true;
+
+        assert_eq!(canonical(&logo_last), expected);
+        assert_eq!(canonical(&shift_last), expected);
+
+        // A modifier key which is already held is not bound twice.
+        let mut repeated = Binding::from_str("Control_R").unwrap();
+        repeated.modifiers.ctrl = true;
+        assert_eq!(
+            canonical(&repeated),
+            Binding::new(Modifiers::new().ctrl(), None)
+        );
+    }
+
+    #[test]
+    fn canonical_form() {
+        let upper = Binding::from_str("Shift+A").unwrap();
+        let lower = Binding::from_str("Shift+a").unwrap();
+
+        assert_eq!(canonical(&upper).to_string(), lower.to_string());
+        assert_eq!(canonical(&lower).to_string(), lower.to_string());
+        assert_eq!(
+            parse("Shift+Ctrl+T")
+                .map(|binding| binding.to_string())
+                .ok(),
+            parse("ctrl shift t")
+                .map(|binding| binding.to_string())
+                .ok()
+        );
+    }
+
+    #[test]
+    fn rejected_modifiers() {
+        assert_eq!(normalize("Hyper+t"), None);
+        assert_eq!(normalize("   "), None);
+    }
+}
@@ -0,0 +1,130 @@
+//! Checks on the commands of custom shortcuts.
+//!
+//! The compositor runs commands through `sh -c`, so words are split the way a POSIX shell would.
+
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+
+/// The executable which will be run by the command, if it is not resolved by the shell at runtime.
+pub fn executable(command: &str) -> Option<String> {
+    let mut rest = command.trim_start();
+
+    loop {
+        let (word, remainder) = next_word(rest)?;
+
+        // Skip environment variable assignments, such as `GDK_BACKEND=x11 app`.
+        let is_assignment = word.split_once('=').is_some_and(|(name, _)| {
+            name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        });
+
+        if !is_assignment {
+            // Expansions and builtins can only be resolved by the shell.
+            if word.is_empty() || word.contains(['$', '`', '~']) || BUILTINS.contains(&&*word) {
+                return None;
+            }
+
+            return Some(word);
+        }
+
+        rest = remainder;
+    }
+}
+
+/// Returns the executable of the command if it could not be found.
+pub fn missing_executable(command: &str) -> Option<String> {
+    let executable = executable(command)?;
+
+    let found = if executable.contains('/') {
+        is_executable(Path::new(&executable))
+    } else {
+        std::env::var_os("PATH").is_some_and(|paths| {
+            std::env::split_paths(&paths).any(|dir| is_executable(&dir.join(&executable)))
+        })
+    };
+
+    (!found).then_some(executable)
+}
+
+/// Shell builtins and keywords which are not found in `PATH`.
+const BUILTINS: &[&str] = &[
+    ".", ":", "cd", "command", "eval", "exec", "export", "if", "for", "case", "while", "{", "(",
+];
+
+fn is_executable(path: &Path) -> bool {
+    path.metadata()
+        .is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+}
+
+/// Splits the next word from the input, removing quotes and escapes.
+fn next_word(input: &str) -> Option<(String, &str)> {
+    let input = input.trim_start();
+    if input.is_empty() {
+        return None;
+    }
+
+    let mut word = String::new();
+    let mut chars = input.char_indices();
+
+    while let Some((pos, c)) = chars.next() {
+        match c {
+            c if c.is_whitespace() => return Some((word, &input[pos..])),
+
+            // Control operators end the command.
+            ';' | '&' | '|' | '<' | '>' => return Some((word, "")),
+
+            '\\' => word.extend(chars.next().map(|(_, c)| c)),
+
+            '\'' => {
+                for (_, c) in chars.by_ref() {
+                    if c == '\'' {
+                        break;
+                    }
+                    word.push(c);
+                }
+            }
+
+            '"' => {
+                while let Some((_, c)) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' => match chars.next() {
+                            Some((_, c @ ('"' | '\\' | '$' | '`'))) => word.push(c),
+                            Some((_, c)) => {
+                                word.push('\\');
+                                word.push(c);
+                            }
+                            None => (),
+                        },
+                        c => word.push(c),
+                    }
+                }
+            }
+
+            c => word.push(c),
+        }
+    }
+
+    Some((word, ""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shell_words() {
+        assert_eq!(executable("firefox --new-window"), Some("firefox".into()));
+        assert_eq!(executable("  'my app' --flag"), Some("my app".into()));
+        assert_eq!(
+            executable(r#""/opt/a b/run" x"#),
+            Some("/opt/a b/run".into())
+        );
+        assert_eq!(executable(r"my\ app"), Some("my app".into()));
+        assert_eq!(executable("GDK_BACKEND=x11 app"), Some("app".into()));
+        assert_eq!(executable("notify-send;true"), Some("notify-send".into()));
+        assert_eq!(executable("$HOME/bin/app"), None);
+        assert_eq!(executable("exec app"), None);
+        assert_eq!(executable(""), None);
+    }
+}
@@ -1,27 +1,233 @@
-use cosmic::iced::{Alignment, Length};
+use super::writer::Writer;
+use cosmic::iced::{color, Alignment, Length};
 use cosmic::widget::{self, button, icon, settings, text};
 use cosmic::{theme, Apply, Element, Task};
 use cosmic_config::{ConfigGet, ConfigSet};
 use cosmic_settings_config::shortcuts::{self, Action, Binding, Shortcuts};
 use cosmic_settings_page as page;
+use i18n_embed::LanguageLoader;
+use icu::collator::{Collator, CollatorOptions};
+use icu::locid::Locale;
+use icu::locid_transform::LocaleDirectionality;
+use once_cell::sync::Lazy;
 use slab::Slab;
 use slotmap::Key;
 use std::borrow::Cow;
 use std::io;
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+/// Key combinations which the compositor may intercept regardless of the shortcuts config.
+const RESERVED_BINDINGS: &[&str] = &[
+    "Ctrl+Alt+Delete",
+    "Ctrl+Alt+BackSpace",
+    "Ctrl+Alt+F1",
+    "Ctrl+Alt+F2",
+    "Ctrl+Alt+F3",
+    "Ctrl+Alt+F4",
+    "Ctrl+Alt+F5",
+    "Ctrl+Alt+F6",
+    "Ctrl+Alt+F7",
+    "Ctrl+Alt+F8",
+    "Ctrl+Alt+F9",
+    "Ctrl+Alt+F10",
+    "Ctrl+Alt+F11",
+    "Ctrl+Alt+F12",
+];
+
+/// The reserved bindings, parsed once.
+static RESERVED: Lazy<Vec<Binding>> = Lazy::new(|| {
+    RESERVED_BINDINGS
+        .iter()
+        .filter_map(|reserved| Binding::from_str(reserved).ok())
+        .collect()
+});
+
+/// Config holding preferences of the settings app itself.
+const SETTINGS_CONFIG: &str = "com.system76.CosmicSettings";
+/// Display modifiers of a binding as symbols rather than words.
+const SYMBOLS_KEY: &str = "shortcut-symbols";
+/// Name the logo modifier of a binding when displaying it.
+const LOGO_NAME_KEY: &str = "shortcut-logo-name";
+/// Order in which the modifiers of a binding are displayed.
+const MODIFIER_ORDER_KEY: &str = "shortcut-modifier-order";
+/// Display the default bindings of each shortcut alongside its current bindings.
+const COMPARE_KEY: &str = "shortcut-compare";
+
+/// Bindings which a shortcut may have, to keep repeated adds from making runaway lists.
+const MAX_BINDINGS: usize = 8;
+
+/// Symbols of each modifier as written by the binding's text form.
+const MODIFIER_SYMBOLS: &[(&str, &str)] =
+    &[("Super", "❖"), ("Ctrl", "⌃"), ("Alt", "⌥"), ("Shift", "⇧")];
+
+/// A modifier key, by which the list of shortcuts can be filtered and their labels ordered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum ModifierKey {
+    Super,
+    Ctrl,
+    Alt,
+    Shift,
+}
+
+impl ModifierKey {
+    const ALL: [Self; 4] = [Self::Super, Self::Ctrl, Self::Alt, Self::Shift];
+
+    /// Name of the modifier as written by the binding's text form.
+    fn name(self) -> &'static str {
+        match self {
+            Self::Super => "Super",
+            Self::Ctrl => "Ctrl",
+            Self::Alt => "Alt",
+            Self::Shift => "Shift",
+        }
+    }
+}
+
+/// Name which the logo modifier is displayed as.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum LogoName {
+    #[default]
+    Super,
+    Meta,
+}
+
+/// Order which the modifiers of a binding are displayed in, from first to last.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct ModifierOrder(pub [ModifierKey; 4]);
+
+impl Default for ModifierOrder {
+    /// Super+Ctrl+Alt+Shift, as bindings are written.
+    fn default() -> Self {
+        Self(ModifierKey::ALL)
+    }
+}
+
+impl ModifierOrder {
+    /// Swaps a modifier with the one displayed before it.
+    #[must_use]
+    pub fn move_earlier(mut self, key: ModifierKey) -> Self {
+        if let Some(position) = self.0.iter().position(|&other| other == key) {
+            if position > 0 {
+                self.0.swap(position - 1, position);
+            }
+        }
+
+        self
+    }
+
+    /// Check that every modifier is ordered exactly once.
+    fn is_valid(self) -> bool {
+        ModifierKey::ALL.iter().all(|key| self.0.contains(key))
+    }
+
+    fn position(self, modifier: &str) -> Option<usize> {
+        self.0.iter().position(|key| key.name() == modifier)
+    }
+}
+
+/// How bindings are displayed, which does not change how they are stored.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LabelStyle {
+    /// Display modifiers as symbols rather than words.
+    pub symbols: bool,
+    pub logo: LogoName,
+    pub order: ModifierOrder,
+}
+
+/// Modifiers which a shortcut must have at least one binding with.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ModifierFilter {
+    pub logo: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+}
+
+impl ModifierFilter {
+    fn get_mut(&mut self, key: ModifierKey) -> &mut bool {
+        match key {
+            ModifierKey::Super => &mut self.logo,
+            ModifierKey::Ctrl => &mut self.ctrl,
+            ModifierKey::Alt => &mut self.alt,
+            ModifierKey::Shift => &mut self.shift,
+        }
+    }
+
+    fn is_active(self) -> bool {
+        self.logo || self.ctrl || self.alt || self.shift
+    }
+
+    /// Check if the binding holds every modifier in the filter.
+    fn matches(self, binding: &Binding) -> bool {
+        (!self.logo || binding.modifiers.logo)
+            && (!self.ctrl || binding.modifiers.ctrl)
+            && (!self.alt || binding.modifiers.alt)
+            && (!self.shift || binding.modifiers.shift)
+    }
+}
 
 #[derive(Clone, Debug)]
 pub enum ShortcutMessage {
+    /// Adds an input for a new binding to the shortcut in context.
     AddKeybinding,
     ApplyReplace,
+    CancelDelete,
     CancelReplace,
+    /// Reverts the input of a binding being edited, without changing the config.
+    ClearInput(usize),
+    /// The shortcuts config was changed, possibly by another application.
+    ConfigChanged,
+    ConfirmDelete,
+    CopyBinding(usize),
     DeleteBinding(usize),
     DeleteShortcut(usize),
+    /// Disables every default binding of the shortcut in context, and removes its other bindings.
+    DisableBindings,
+    DismissWriteError,
+    /// Handled by the custom shortcuts page, which owns the form for adding shortcuts.
+    DuplicateShortcut(usize),
     EditBinding(usize, bool),
+    EditCommand(bool),
+    EditDescription(bool),
     InputBinding(usize, String),
+    InputCommand(String),
+    InputDescription(String),
+    LookupInput(String),
+    LookupSubmit,
+    RecoverApply,
+    RecoverCancel,
+    Redo,
+    /// Replaces a custom binding with a disabled default binding of the same action.
+    ResetBinding(usize),
     ResetBindings,
+    /// Asks to reset every shortcut of the page to its defaults.
+    ResetCategory,
+    ResetCategoryApply,
+    ResetCategoryCancel,
+    Search(String),
+    SetLogoName(LogoName),
+    SetModifierOrder(ModifierOrder),
+    ShowRecovery,
     ShowShortcut(usize, String),
     SubmitBinding(usize),
+    /// Fills in the input of a binding with a free binding.
+    SuggestBinding(usize, Binding),
+    SubmitCommand,
+    SubmitDescription,
+    ToggleCompare(bool),
+    ToggleCustomFilter(bool),
+    /// Enables or disables a default binding.
+    ToggleDefault(Binding, bool),
+    ToggleExpanded(usize),
+    ToggleModifiedFilter(bool),
+    ToggleModifierFilter(ModifierKey, bool),
+    ToggleSymbols(bool),
+    Undo,
+    /// Submits a free binding suggested by the replace dialog, instead of replacing.
+    UseSuggestion(Binding),
+    /// A queued write of the shortcuts config failed.
+    WriteFailed(String),
 }
 
 #[derive(Debug)]
@@ -39,15 +245,23 @@ pub struct ShortcutModel {
     pub action: Action,
     pub bindings: Slab<ShortcutBinding>,
     pub description: String,
+    /// Default bindings of the action which have been disabled.
+    pub disabled: Vec<Binding>,
+    /// Show every binding in the list, rather than only the first few.
+    pub expanded: bool,
+    /// Number of changes from the default bindings.
+    ///
+    /// Added and removed bindings count once each, where a removed default includes one
+    /// disabled by an `Action::Disable` entry. A default replaced by another binding counts once.
     pub modified: u16,
 }
 
 impl ShortcutModel {
     pub fn new(defaults: &Shortcuts, shortcuts: &Shortcuts, action: Action) -> Self {
-        let (bindings, modified) =
+        let (bindings, added) =
             shortcuts
                 .shortcuts(&action)
-                .fold((Slab::new(), 0), |(mut slab, modified), binding| {
+                .fold((Slab::new(), 0), |(mut slab, added), binding| {
                     let is_default = defaults.0.get(binding) == Some(&action);
 
                     slab.insert(ShortcutBinding {
@@ -58,9 +272,31 @@ impl ShortcutModel {
                         is_default,
                     });
 
-                    (slab, if is_default { modified } else { modified + 1 })
+                    (slab, if is_default { added } else { added + 1 })
                 });
 
+        let removed = defaults
+            .0
+            .iter()
+            .filter(|(binding, default_action)| {
+                **default_action == action
+                    && !bindings
+                        .iter()
+                        .any(|(_, shortcut)| shortcut.binding == **binding)
+            })
+            .fold(0, |removed: u16, _| removed + 1);
+
+        let mut disabled = defaults
+            .0
+            .iter()
+            .filter(|(binding, default_action)| {
+                **default_action == action && shortcuts.0.get(binding) == Some(&Action::Disable)
+            })
+            .map(|(binding, _)| binding.clone())
+            .collect::<Vec<_>>();
+
+        disabled.sort_by_cached_key(ToString::to_string);
+
         let mut localized_description = super::localize_action(&action);
         if let Action::Spawn(_) = &action {
             localized_description = bindings
@@ -72,18 +308,102 @@ impl ShortcutModel {
 
         Self {
             description: localized_description,
-            modified: defaults.0.iter().filter(|(_, a)| **a == action).fold(
-                modified,
-                |modified, (binding, _)| {
-                    if bindings.iter().any(|(_, model)| model.binding == *binding) {
-                        modified
-                    } else {
-                        modified + 1
-                    }
-                },
-            ),
+            // A default binding replaced by another is one change, rather than a removal and an addition.
+            modified: added.max(removed),
             action,
             bindings,
+            disabled,
+            expanded: false,
+        }
+    }
+
+    /// Another binding of the action which the input of a binding would duplicate.
+    fn duplicate_of(&self, id: usize) -> Option<&ShortcutBinding> {
+        let binding = super::binding::parse(&self.bindings.get(id)?.input).ok()?;
+
+        self.bindings
+            .iter()
+            .find(|(key, shortcut)| {
+                *key != id && shortcut.binding.is_set() && shortcut.binding == binding
+            })
+            .map(|(_, shortcut)| shortcut)
+    }
+
+    /// The disabled default which a binding most likely replaced, to be restored in its place.
+    ///
+    /// A default of the same key is preferred, then the one with the most modifiers in common,
+    /// and otherwise the first in the order they are listed.
+    fn replaced_default(&self, binding: &Binding) -> Option<&Binding> {
+        let common_modifiers = |default: &Binding| {
+            [
+                default.modifiers.logo == binding.modifiers.logo,
+                default.modifiers.ctrl == binding.modifiers.ctrl,
+                default.modifiers.alt == binding.modifiers.alt,
+                default.modifiers.shift == binding.modifiers.shift,
+            ]
+            .into_iter()
+            .filter(|same| *same)
+            .count()
+        };
+
+        // Reversed, since the last of several equal maximums is picked.
+        self.disabled
+            .iter()
+            .rev()
+            .max_by_key(|default| (default.key == binding.key, common_modifiers(default)))
+    }
+
+    /// Removes rows which were added for new bindings but left empty, unless they are being edited.
+    fn prune_empty(&mut self) {
+        self.bindings.retain(|_, shortcut| {
+            shortcut.editing || shortcut.binding.is_set() || !shortcut.input.trim().is_empty()
+        });
+    }
+
+    /// Keeps the view state of the model it replaces, including bindings which are being edited.
+    ///
+    /// Bindings keep their keys and widget IDs, so that messages and focus which refer to
+    /// a binding still refer to it after a reload.
+    fn restore(&mut self, mut previous: ShortcutModel) {
+        self.expanded = previous.expanded;
+
+        let mut kept = Vec::with_capacity(self.bindings.len());
+        let mut added = Vec::new();
+
+        for mut shortcut in self.bindings.drain() {
+            let key = previous
+                .bindings
+                .iter()
+                .find(|(_, s)| s.binding.is_set() && s.binding == shortcut.binding)
+                .map(|(key, _)| key);
+
+            let Some(key) = key else {
+                added.push(shortcut);
+                continue;
+            };
+
+            let existing = previous.bindings.remove(key);
+            shortcut.id = existing.id;
+
+            if existing.editing {
+                shortcut.editing = true;
+                shortcut.input = existing.input;
+            }
+
+            kept.push((key, shortcut));
+        }
+
+        // Inputs for new bindings are not yet stored in the config.
+        kept.extend(
+            previous
+                .bindings
+                .into_iter()
+                .filter(|(_, s)| s.editing && !s.binding.is_set()),
+        );
+
+        self.bindings = kept.into_iter().collect();
+        for shortcut in added {
+            self.bindings.insert(shortcut);
         }
     }
 }
@@ -93,29 +413,104 @@ pub struct Model {
     pub entity: page::Entity,
     pub defaults: Shortcuts,
     pub replace_dialog: Option<(usize, Binding, Action, String)>,
+    pub search: String,
+    /// Key combination to find the owning shortcut of.
+    pub lookup: String,
+    pub lookup_result: Option<String>,
+    /// Only display shortcuts which differ from their defaults.
+    pub modified_only: bool,
+    /// Only display user-created shortcuts.
+    pub custom_only: bool,
+    /// Only display shortcuts with a binding holding these modifiers.
+    pub modifier_filter: ModifierFilter,
+    /// How bindings are displayed.
+    pub label: LabelStyle,
+    /// Display the default bindings of each shortcut alongside its current bindings.
+    pub compare: bool,
     pub shortcut_models: Slab<ShortcutModel>,
+    /// Indices of `shortcut_models` ordered by their localized descriptions.
+    pub sorted: Vec<usize>,
     pub shortcut_context: Option<usize>,
+    /// Command of the custom shortcut in context, while it is being edited.
+    pub command_input: Option<String>,
+    pub command_id: widget::Id,
+    /// Label of the custom shortcut in context, while it is being edited.
+    pub description_input: Option<String>,
+    pub description_id: widget::Id,
+    /// Executable of the command in context which could not be found.
+    pub command_warning: Option<String>,
     pub config: cosmic_config::Config,
     pub custom: bool,
     pub actions: fn(&Shortcuts, &Shortcuts) -> Slab<ShortcutModel>,
+    /// Custom shortcuts prior to each change, for undoing it.
+    pub undo: Vec<Shortcuts>,
+    /// Custom shortcuts prior to each undo, for redoing the change.
+    pub redo: Vec<Shortcuts>,
+    /// Custom shortcuts as last read or written by this page, which edits are applied to.
+    ///
+    /// Writes are queued, so this is ahead of the config while they are pending.
+    pub loaded: Mutex<Shortcuts>,
+    /// Error from reading the custom shortcuts config.
+    pub read_error: Option<String>,
+    /// Offer to set aside the unreadable custom shortcuts config.
+    pub recovery_dialog: bool,
+    /// Error from the last failed write to the shortcuts config.
+    pub write_error: Option<String>,
+    /// Custom shortcut with several bindings which is awaiting confirmation to be deleted.
+    pub delete_dialog: Option<usize>,
+    /// Confirm resetting every shortcut of the page.
+    pub reset_category_dialog: bool,
+    /// Cancels the watch on the shortcuts config.
+    pub watcher: Option<tokio::sync::oneshot::Sender<()>>,
+    /// Queues writes of the custom shortcuts, so that they happen off the update thread.
+    pub writer: Arc<Writer>,
 }
 
 impl Default for Model {
     fn default() -> Self {
+        Self::new(shortcuts::context().unwrap(), Writer::shared())
+    }
+}
+
+impl Model {
+    /// A page reading from the config, whose changes are queued to the writer.
+    pub(super) fn new(config: cosmic_config::Config, writer: Arc<Writer>) -> Self {
         Self {
             entity: page::Entity::null(),
             defaults: Shortcuts::default(),
             replace_dialog: None,
+            search: String::new(),
+            lookup: String::new(),
+            lookup_result: None,
+            modified_only: false,
+            custom_only: false,
+            modifier_filter: ModifierFilter::default(),
+            label: LabelStyle::default(),
+            compare: false,
             shortcut_models: Slab::new(),
+            sorted: Vec::new(),
             shortcut_context: None,
-            config: shortcuts::context().unwrap(),
+            command_input: None,
+            command_id: widget::Id::unique(),
+            command_warning: None,
+            description_input: None,
+            description_id: widget::Id::unique(),
+            config,
             custom: false,
             actions: |_, _| Slab::new(),
+            undo: Vec::new(),
+            redo: Vec::new(),
+            loaded: Mutex::new(Shortcuts::default()),
+            read_error: None,
+            recovery_dialog: false,
+            write_error: None,
+            delete_dialog: None,
+            reset_category_dialog: false,
+            watcher: None,
+            writer,
         }
     }
-}
 
-impl Model {
     pub fn actions(mut self, actions: fn(&Shortcuts, &Shortcuts) -> Slab<ShortcutModel>) -> Self {
         self.actions = actions;
         self
@@ -126,11 +521,103 @@ impl Model {
         self
     }
 
-    /// Adds a new binding to the shortcuts config
-    pub(super) fn config_add(&self, action: Action, binding: Binding) {
+    /// Shortcuts of the page in the order they are listed, with the bindings of each.
+    pub fn shortcuts(&self) -> impl Iterator<Item = (&Action, Vec<&Binding>)> + '_ {
+        self.sorted
+            .iter()
+            .filter_map(|&id| self.shortcut_models.get(id))
+            .map(|model| {
+                let bindings = model
+                    .bindings
+                    .iter()
+                    .map(|(_, shortcut)| &shortcut.binding)
+                    .filter(|binding| binding.is_set())
+                    .collect();
+
+                (&model.action, bindings)
+            })
+    }
+
+    /// Bindings which currently trigger the action, as shown in the list.
+    #[must_use]
+    pub fn bindings_for(&self, action: &Action) -> Vec<Binding> {
+        self.shortcut_models
+            .iter()
+            .filter(|(_, model)| model.action == *action)
+            .flat_map(|(_, model)| model.bindings.iter())
+            .map(|(_, shortcut)| &shortcut.binding)
+            .filter(|binding| binding.is_set())
+            .cloned()
+            .collect()
+    }
+
+    /// Adds a new binding to the shortcuts config, replacing any existing binding of the same keys
+    pub(super) fn config_add(
+        &self,
+        action: Action,
+        binding: Binding,
+    ) -> Result<(), cosmic_config::Error> {
+        self.config_edit(|shortcuts| insert_custom(&self.defaults, shortcuts, binding, action))
+    }
+
+    /// Applies several changes to the shortcuts config with a single write.
+    pub(super) fn config_edit(
+        &self,
+        edit: impl FnOnce(&mut Shortcuts),
+    ) -> Result<(), cosmic_config::Error> {
         let mut shortcuts = self.shortcuts_config();
-        shortcuts.0.insert(binding, action);
-        self.shortcuts_config_set(shortcuts);
+        edit(&mut shortcuts);
+        self.shortcuts_config_set(shortcuts)
+    }
+
+    /// Stores a backup of the custom shortcuts before they are reset.
+    pub(super) fn backup(&self) {
+        let custom = self.shortcuts_config();
+        if custom.0.is_empty() {
+            return;
+        }
+
+        match super::backup::create(custom) {
+            Ok(path) => tracing::info!(?path, "created a backup of the custom shortcuts"),
+            Err(why) => tracing::error!(?why, "failed to back up the custom shortcuts"),
+        }
+    }
+
+    /// Restores the default bindings of the actions with a single write.
+    ///
+    /// Custom bindings of the actions are removed, as are overrides of their default bindings,
+    /// such as disabling them.
+    fn reset_actions(&self, actions: &[Action]) -> Result<(), cosmic_config::Error> {
+        self.config_edit(|shortcuts| {
+            shortcuts.0.retain(|binding, action| {
+                !actions.contains(action)
+                    && !self
+                        .defaults
+                        .0
+                        .get(binding)
+                        .is_some_and(|default| actions.contains(default))
+            });
+        })
+    }
+
+    /// Resets every shortcut of the page, when any of them differ from their defaults.
+    pub(super) fn reset_category_button(&self) -> Option<Element<'_, ShortcutMessage>> {
+        if self.custom
+            || self
+                .shortcut_models
+                .iter()
+                .all(|(_, model)| model.modified == 0)
+        {
+            return None;
+        }
+
+        widget::button::standard(fl!("reset-category-shortcuts"))
+            .on_press(ShortcutMessage::ResetCategory)
+            .apply(widget::container)
+            .width(Length::Fill)
+            .align_x(Alignment::End)
+            .apply(Element::from)
+            .apply(Some)
     }
 
     /// Check if a binding is already set
@@ -143,20 +630,200 @@ impl Model {
     }
 
     /// Removes a binding from the shortcuts config
-    pub(super) fn config_remove(&self, binding: &Binding) {
-        let mut shortcuts = self.shortcuts_config();
-        shortcuts.0.retain(|b, _| b != binding);
-        self.shortcuts_config_set(shortcuts);
+    pub(super) fn config_remove(&self, binding: &Binding) -> Result<(), cosmic_config::Error> {
+        self.config_edit(|shortcuts| shortcuts.0.retain(|b, _| b != binding))
     }
 
     pub(super) fn context_drawer(&self) -> Option<Element<'_, ShortcutMessage>> {
         self.shortcut_context
             .as_ref()
-            .map(|id| context_drawer(&self.shortcut_models, *id, self.custom))
+            .map(|id| context_drawer(self, *id))
+    }
+
+    /// Controls for filtering the list of shortcuts.
+    pub(super) fn filters(&self) -> Element<'_, ShortcutMessage> {
+        let cosmic::cosmic_theme::Spacing {
+            space_xxs, space_m, ..
+        } = theme::active().cosmic().spacing;
+
+        let search = widget::search_input(fl!("type-to-search"), &self.search)
+            .width(314)
+            .on_clear(ShortcutMessage::Search(String::new()))
+            .on_input(ShortcutMessage::Search)
+            .apply(widget::container)
+            .center_x(Length::Fill);
+
+        let lookup_input = widget::text_input(fl!("shortcut-lookup"), &self.lookup)
+            .width(314)
+            .on_input(ShortcutMessage::LookupInput)
+            .on_submit(ShortcutMessage::LookupSubmit);
+
+        let lookup = widget::column::with_capacity(2)
+            .spacing(space_xxs)
+            .align_x(Alignment::Center)
+            .push(lookup_input)
+            .push_maybe(self.lookup_result.as_deref().map(text::caption))
+            .apply(widget::container)
+            .center_x(Length::Fill);
+
+        widget::column::with_capacity(3)
+            .spacing(space_m)
+            .push(search)
+            .push(lookup)
+            .push(self.filter_toggles())
+            .into()
+    }
+
+    /// Toggles for narrowing down the list of shortcuts.
+    pub(super) fn filter_toggles(&self) -> Element<'_, ShortcutMessage> {
+        let is_custom =
+            |(_, model): (usize, &ShortcutModel)| matches!(model.action, Action::Spawn(_));
+
+        // Only offer the custom filter when the list contains both kinds of shortcuts.
+        let mixed = self.shortcut_models.iter().any(is_custom)
+            && !self.shortcut_models.iter().all(is_custom);
+
+        let space_s = theme::active().cosmic().spacing.space_s;
+
+        let modifier_keys = [
+            (ModifierKey::Super, self.modifier_filter.logo),
+            (ModifierKey::Ctrl, self.modifier_filter.ctrl),
+            (ModifierKey::Alt, self.modifier_filter.alt),
+            (ModifierKey::Shift, self.modifier_filter.shift),
+        ];
+
+        let mut modifiers = widget::row::with_capacity(4).spacing(space_s);
+        for (key, checked) in modifier_keys {
+            let checkbox = widget::checkbox(modifier_name(key.name(), self.label), checked)
+                .on_toggle(move |enable| ShortcutMessage::ToggleModifierFilter(key, enable));
+            modifiers = modifiers.push(checkbox);
+        }
+
+        let mut section = settings::section()
+            .add(settings::item(
+                fl!("shortcut-filter", "modified"),
+                widget::toggler(self.modified_only)
+                    .on_toggle(ShortcutMessage::ToggleModifiedFilter),
+            ))
+            .add(settings::item(
+                fl!("shortcut-filter", "modifiers"),
+                modifiers,
+            ));
+
+        if mixed {
+            section = section.add(settings::item(
+                fl!("shortcut-filter", "custom"),
+                widget::toggler(self.custom_only).on_toggle(ShortcutMessage::ToggleCustomFilter),
+            ));
+        }
+
+        section.into()
+    }
+
+    /// Preferences for how bindings are displayed, which apply to every shortcuts page.
+    pub(super) fn display_settings<'a>(&'a self, title: &'a str) -> Element<'a, ShortcutMessage> {
+        let space_xxs = theme::active().cosmic().spacing.space_xxs;
+        let order = self.label.order;
+
+        // Pressing a modifier moves it ahead of the one before it, so any order can be reached.
+        let mut modifier_order = widget::row::with_capacity(4).spacing(space_xxs);
+        for (position, key) in order.0.into_iter().enumerate() {
+            let button = widget::button::standard(modifier_name(key.name(), self.label))
+                .on_press_maybe(
+                    (position > 0)
+                        .then(|| ShortcutMessage::SetModifierOrder(order.move_earlier(key))),
+                );
+
+            modifier_order = modifier_order.push(widget::tooltip(
+                button,
+                text::body(fl!("shortcut-modifier-order", "move")),
+                widget::tooltip::Position::Top,
+            ));
+        }
+
+        settings::section()
+            .title(title)
+            .add(settings::item(
+                fl!("shortcut-symbols"),
+                widget::toggler(self.label.symbols).on_toggle(ShortcutMessage::ToggleSymbols),
+            ))
+            .add(settings::item(
+                fl!("shortcut-logo-meta"),
+                widget::toggler(self.label.logo == LogoName::Meta).on_toggle(|meta| {
+                    ShortcutMessage::SetLogoName(if meta {
+                        LogoName::Meta
+                    } else {
+                        LogoName::Super
+                    })
+                }),
+            ))
+            .add(settings::item(
+                fl!("shortcut-modifier-order"),
+                modifier_order,
+            ))
+            .add(settings::item(
+                fl!("shortcut-compare"),
+                widget::toggler(self.compare).on_toggle(ShortcutMessage::ToggleCompare),
+            ))
+            .into()
+    }
+
+    /// Check if a shortcut passes the active filters.
+    fn is_visible(&self, model: &ShortcutModel, search: &str) -> bool {
+        (!self.modified_only || model.modified > 0)
+            && (!self.custom_only || matches!(model.action, Action::Spawn(_)))
+            && (!self.modifier_filter.is_active()
+                || model
+                    .bindings
+                    .iter()
+                    .any(|(_, shortcut)| self.modifier_filter.matches(&shortcut.binding)))
+            && model.description.to_lowercase().contains(search)
     }
 
     pub(super) fn dialog(&self) -> Option<Element<'_, ShortcutMessage>> {
-        if let Some(&(id, _, _, ref action)) = self.replace_dialog.as_ref() {
+        if let Some(dialog) = self.read_error_dialog() {
+            return Some(dialog);
+        }
+
+        if let Some(dialog) = self.write_error_dialog() {
+            return Some(dialog);
+        }
+
+        if let Some(model) = self
+            .delete_dialog
+            .and_then(|id| self.shortcut_models.get(id))
+        {
+            let dialog = widget::dialog()
+                .title(fl!("delete-shortcut-dialog"))
+                .icon(icon::from_name("dialog-warning").size(64))
+                .body(fl!(
+                    "delete-shortcut-dialog",
+                    "desc",
+                    name = model.description.as_str(),
+                    count = model.bindings.len()
+                ))
+                .primary_action(
+                    button::destructive(fl!("delete-shortcut-dialog", "apply"))
+                        .on_press(ShortcutMessage::ConfirmDelete),
+                )
+                .secondary_action(
+                    button::standard(fl!("cancel")).on_press(ShortcutMessage::CancelDelete),
+                );
+
+            return Some(dialog.into());
+        }
+
+        if self.reset_category_dialog {
+            return Some(reset_dialog(
+                fl!("reset-category-shortcuts"),
+                fl!("reset-category-shortcuts", "dialog"),
+                fl!("reset-category-shortcuts", "desc"),
+                ShortcutMessage::ResetCategoryApply,
+                ShortcutMessage::ResetCategoryCancel,
+            ));
+        }
+
+        if let Some(&(id, ref new_binding, _, ref action)) = self.replace_dialog.as_ref() {
             if let Some(short_id) = self.shortcut_context {
                 if let Some(model) = self.shortcut_models.get(short_id) {
                     if let Some(shortcut) = model.bindings.get(id) {
@@ -166,13 +833,17 @@ impl Model {
                         let secondary_action = button::standard(fl!("cancel"))
                             .on_press(ShortcutMessage::CancelReplace);
 
-                        let dialog = widget::dialog()
+                        // A similar binding which is free, offered instead of replacing.
+                        let suggestion =
+                            next_free_binding(new_binding, &self.shortcuts_system_config());
+
+                        let mut dialog = widget::dialog()
                             .title(fl!("replace-shortcut-dialog"))
                             .icon(icon::from_name("dialog-warning").size(64))
                             .body(fl!(
                                 "replace-shortcut-dialog",
                                 "desc",
-                                shortcut = shortcut.input.clone(),
+                                shortcut = binding_label(new_binding, self.label),
                                 name = shortcut
                                     .binding
                                     .description
@@ -183,6 +854,17 @@ impl Model {
                             .primary_action(primary_action)
                             .secondary_action(secondary_action);
 
+                        if let Some(suggestion) = suggestion {
+                            dialog = dialog.tertiary_action(
+                                button::standard(fl!(
+                                    "replace-shortcut-dialog",
+                                    "suggestion",
+                                    shortcut = binding_label(&suggestion, self.label)
+                                ))
+                                .on_press(ShortcutMessage::UseSuggestion(suggestion)),
+                            );
+                        }
+
                         return Some(dialog.into());
                     }
                 }
@@ -192,173 +874,952 @@ impl Model {
         None
     }
 
-    pub(super) fn on_enter(&mut self) {
-        let mut shortcuts = self.config.get::<Shortcuts>("defaults").unwrap_or_default();
-        self.defaults = shortcuts.clone();
-
-        if let Ok(custom) = self.config.get::<Shortcuts>("custom") {
-            for (binding, action) in custom.0 {
-                shortcuts.0.remove(&binding);
-                shortcuts.0.insert(binding, action);
-            }
+    /// Offers to set aside the unreadable custom shortcuts config.
+    pub(super) fn read_error_dialog(&self) -> Option<Element<'_, ShortcutMessage>> {
+        if !self.recovery_dialog {
+            return None;
         }
 
-        self.shortcut_models = (self.actions)(&self.defaults, &shortcuts);
+        let why = self.read_error.as_ref()?;
+
+        widget::dialog()
+            .title(fl!("shortcuts-recover", "dialog"))
+            .icon(icon::from_name("dialog-warning").size(64))
+            .body(fl!("shortcuts-recover", "desc", why = why.as_str()))
+            .primary_action(
+                button::suggested(fl!("shortcuts-recover", "apply"))
+                    .on_press(ShortcutMessage::RecoverApply),
+            )
+            .secondary_action(
+                button::standard(fl!("cancel")).on_press(ShortcutMessage::RecoverCancel),
+            )
+            .apply(Element::from)
+            .apply(Some)
     }
 
-    pub(super) fn on_clear(&mut self) {
-        self.shortcut_models.clear();
-        self.shortcut_models.shrink_to_fit();
+    /// Reports the last failed write to the shortcuts config, until it is dismissed.
+    pub(super) fn write_error_dialog(&self) -> Option<Element<'_, ShortcutMessage>> {
+        let why = self.write_error.as_ref()?;
+
+        widget::dialog()
+            .title(fl!("shortcuts-write-error"))
+            .icon(icon::from_name("dialog-error").size(64))
+            .body(fl!("shortcuts-write-error", "desc", why = why.as_str()))
+            .primary_action(
+                button::standard(fl!("ok")).on_press(ShortcutMessage::DismissWriteError),
+            )
+            .apply(Element::from)
+            .apply(Some)
     }
 
-    /// Gets the custom configuration for keyboard shortcuts.
-    pub(super) fn shortcuts_config(&self) -> Shortcuts {
-        match self.config.get::<Shortcuts>("custom") {
-            Ok(shortcuts) => shortcuts,
+    /// Reads the custom shortcuts, recording an error if they could not be read.
+    pub(super) fn load_custom(&mut self) -> Option<Shortcuts> {
+        // The config is outdated while changes to it are still being written.
+        if let Some(pending) = self.writer.pending() {
+            self.loaded.get_mut().unwrap().clone_from(&pending);
+            return Some(pending);
+        }
+
+        let previous_error = self.read_error.take();
+
+        let custom = match self.config.get::<Shortcuts>("custom") {
+            Ok(custom) => Some(custom),
             Err(cosmic_config::Error::GetKey(_, why)) if why.kind() == io::ErrorKind::NotFound => {
-                Shortcuts::default()
-            }
-            Err(why) => {
-                tracing::error!(?why, "unable to get the current shortcuts config");
-                Shortcuts::default()
+                None
             }
+            // Rewrite the config once if it was written in a legacy format.
+            Err(why) => match super::migrate::custom() {
+                Some(custom) => {
+                    tracing::info!("migrated the custom shortcuts from a legacy format");
+                    _ = self.shortcuts_config_set(custom.clone());
+                    Some(custom)
+                }
+
+                None => {
+                    tracing::error!(?why, "unable to read the custom shortcuts config");
+                    self.read_error = Some(why.to_string());
+
+                    // Offer to recover once, rather than every time the shortcuts are reloaded.
+                    if previous_error.is_none() {
+                        self.recovery_dialog = true;
+                    }
+
+                    None
+                }
+            },
+        };
+
+        *self.loaded.get_mut().unwrap() = custom.clone().unwrap_or_default();
+        custom
+    }
+
+    /// Warns that the custom shortcuts could not be read.
+    pub(super) fn read_warning(&self) -> Option<Element<'_, ShortcutMessage>> {
+        let why = self.read_error.as_ref()?;
+        let space_s = theme::active().cosmic().spacing.space_s;
+
+        widget::row::with_capacity(3)
+            .spacing(space_s)
+            .align_y(Alignment::Center)
+            .push(icon::from_name("dialog-warning-symbolic").size(24))
+            .push(text::body(fl!("shortcuts-read-error", why = why.as_str())).width(Length::Fill))
+            .push(
+                button::standard(fl!("shortcuts-recover")).on_press(ShortcutMessage::ShowRecovery),
+            )
+            .apply(widget::container)
+            .padding(space_s)
+            .width(Length::Fill)
+            .class(theme::Container::Card)
+            .apply(Element::from)
+            .apply(Some)
+    }
+
+    /// Builds the list from scratch, dropping any view state.
+    ///
+    /// Edits made while on the page should use [`Self::reload`] or [`Self::refresh`] instead,
+    /// so that the rows keep their widget state and the list keeps its scroll position.
+    pub(super) fn on_enter(&mut self) {
+        self.defaults = self.config.get::<Shortcuts>("defaults").unwrap_or_default();
+        let shortcuts = self.load_shortcuts();
+        self.shortcut_models = (self.actions)(&self.defaults, &shortcuts);
+        self.sort();
+        self.load_preferences();
+    }
+
+    /// Reads the display preferences, which may have been changed on another shortcuts page.
+    pub(super) fn load_preferences(&mut self) {
+        if let Ok(context) = cosmic_config::Config::new(SETTINGS_CONFIG, 1) {
+            self.label = LabelStyle {
+                symbols: context.get::<bool>(SYMBOLS_KEY).unwrap_or_default(),
+                logo: context.get::<LogoName>(LOGO_NAME_KEY).unwrap_or_default(),
+                // An order edited by hand may repeat or omit modifiers.
+                order: context
+                    .get::<ModifierOrder>(MODIFIER_ORDER_KEY)
+                    .ok()
+                    .filter(|order| order.is_valid())
+                    .unwrap_or_default(),
+            };
+            self.compare = context.get::<bool>(COMPARE_KEY).unwrap_or_default();
         }
     }
 
-    /// Gets the system configuration for keyboard shortcuts.
-    pub(super) fn shortcuts_system_config(&self) -> Shortcuts {
-        let mut shortcuts = self.config.get::<Shortcuts>("defaults").unwrap_or_default();
+    /// Reads the custom shortcuts, and applies them over the defaults read on entering the page.
+    fn load_shortcuts(&mut self) -> Shortcuts {
+        let mut shortcuts = self.defaults.clone();
 
-        if let Ok(custom) = self.config.get::<Shortcuts>("custom") {
-            shortcuts.0.extend(custom.0);
+        if let Some(custom) = self.load_custom() {
+            for (binding, action) in custom.0 {
+                shortcuts.0.remove(&binding);
+                shortcuts.0.insert(binding, action);
+            }
         }
 
         shortcuts
     }
 
-    /// Writes a new configuration to the keyboard shortcuts config file.
-    pub(super) fn shortcuts_config_set(&self, shortcuts: Shortcuts) {
-        if let Err(why) = self.config.set("custom", shortcuts) {
-            tracing::error!(?why, "failed to write shortcuts config");
+    /// Updates the models of actions whose bindings were changed, leaving the rest untouched.
+    pub(super) fn refresh(&mut self, actions: &[Action]) {
+        // Custom shortcuts are grouped by command, so a change may add or remove a model.
+        if self.custom {
+            self.reload();
+            return;
+        }
+
+        let shortcuts = self.load_shortcuts();
+
+        for (_, model) in self.shortcut_models.iter_mut() {
+            if actions.contains(&model.action) {
+                let updated = ShortcutModel::new(&self.defaults, &shortcuts, model.action.clone());
+                let previous = std::mem::replace(model, updated);
+                model.restore(previous);
+            }
         }
     }
 
-    #[allow(clippy::too_many_lines)]
-    pub(super) fn update(&mut self, message: ShortcutMessage) -> Task<crate::app::Message> {
-        match message {
-            ShortcutMessage::AddKeybinding => {
-                if let Some(short_id) = self.shortcut_context {
-                    if let Some(model) = self.shortcut_models.get_mut(short_id) {
-                        // If an empty entry exists, focus it instead of creating a new input.
-                        for (_, shortcut) in &mut model.bindings {
-                            if shortcut.binding.is_set()
-                                || Binding::from_str(&shortcut.input).is_ok()
-                            {
-                                continue;
-                            }
+    /// Reloads the shortcuts from the config, keeping bindings which are being edited.
+    pub(super) fn reload(&mut self) {
+        let previous = std::mem::take(&mut self.shortcut_models);
+        self.on_enter();
 
-                            shortcut.input.clear();
+        let models = std::mem::replace(&mut self.shortcut_models, previous);
+        self.replace_models(models);
+    }
 
-                            return widget::text_input::focus(shortcut.id.clone());
-                        }
+    /// Lists new models, keeping the view state of the shortcuts which are still listed.
+    ///
+    /// The shortcut in context and the one awaiting deletion are found again by their action,
+    /// since their models may be at other indices.
+    pub(super) fn replace_models(&mut self, models: Slab<ShortcutModel>) {
+        let action_of = |id: Option<usize>, models: &Slab<ShortcutModel>| {
+            id.and_then(|id| models.get(id))
+                .map(|model| model.action.clone())
+        };
 
-                        // Create a new input and focus it.
-                        let id = widget::Id::unique();
-                        model.bindings.insert(ShortcutBinding {
-                            id: id.clone(),
-                            binding: Binding::default(),
-                            input: String::new(),
-                            editing: true,
-                            is_default: false,
-                        });
-
-                        return widget::text_input::focus(id);
-                    }
-                }
-            }
+        let context = action_of(self.shortcut_context, &self.shortcut_models);
+        let deleting = action_of(self.delete_dialog, &self.shortcut_models);
 
-            ShortcutMessage::ApplyReplace => {
-                if let Some((id, new_binding, ..)) = self.replace_dialog.take() {
-                    if let Some(short_id) = self.shortcut_context {
-                        // Remove conflicting bindings that are saved on disk.
-                        self.config_remove(&new_binding);
+        let mut previous = std::mem::replace(&mut self.shortcut_models, models);
 
-                        // Clear any binding that matches this in the current model
-                        for (_, model) in &mut self.shortcut_models {
-                            if let Some(id) = model
-                                .bindings
-                                .iter()
-                                .find(|(_, shortcut)| shortcut.binding == new_binding)
-                                .map(|(id, _)| id)
-                            {
-                                model.bindings.remove(id);
-                                break;
-                            }
-                        }
+        let find = |action: Option<Action>, models: &Slab<ShortcutModel>| {
+            action.and_then(|action| {
+                models
+                    .iter()
+                    .find(|(_, model)| model.action == action)
+                    .map(|(id, _)| id)
+            })
+        };
 
-                        // Update the current model and save the binding to disk.
-                        if let Some(model) = self.shortcut_models.get_mut(short_id) {
-                            if let Some(shortcut) = model.bindings.get_mut(id) {
-                                let prev_binding = shortcut.binding.clone();
+        self.shortcut_context = find(context, &self.shortcut_models);
+        self.delete_dialog = find(deleting, &self.shortcut_models);
 
-                                shortcut.binding = new_binding.clone();
-                                shortcut.input.clear();
-                                shortcut.editing = false;
+        // The binding awaiting replacement belongs to the shortcut in context.
+        if self.shortcut_context.is_none() {
+            self.replace_dialog = None;
+        }
 
-                                let action = model.action.clone();
-                                self.config_remove(&prev_binding);
-                                self.config_add(action, new_binding);
-                            }
-                        }
+        for previous in previous.drain() {
+            if let Some((_, model)) = self
+                .shortcut_models
+                .iter_mut()
+                .find(|(_, model)| model.action == previous.action)
+            {
+                model.restore(previous);
+            }
+        }
 
-                        self.on_enter();
+        self.sort();
+    }
+
+    /// Reloads the shortcuts whenever the config is changed, and reports failed writes.
+    pub(super) fn watch(
+        &mut self,
+        sender: tokio::sync::mpsc::Sender<crate::pages::Message>,
+        message_map: fn(ShortcutMessage) -> crate::pages::Message,
+    ) {
+        if self.watcher.is_some() {
+            return;
+        }
+
+        self.writer.report_to(sender.clone(), message_map);
+
+        let config = self.config.clone();
+
+        self.watcher = Some(crate::utils::forward_event_loop(
+            sender,
+            message_map,
+            move |mut tx| async move {
+                let watcher = config.watch(move |_config, keys| {
+                    if keys.iter().any(|key| key == "custom" || key == "defaults") {
+                        _ = tx.try_send(ShortcutMessage::ConfigChanged);
                     }
+                });
+
+                match watcher {
+                    // Keep the watcher alive until the page is left.
+                    Ok(_watcher) => futures::future::pending::<()>().await,
+                    Err(why) => tracing::error!(?why, "failed to watch the shortcuts config"),
                 }
+            },
+        ));
+    }
+
+    /// Removes a shortcut along with all of its bindings.
+    fn delete_shortcut(&mut self, id: usize) {
+        // Only custom shortcuts can be deleted, since built-in actions always have a row.
+        match self.shortcut_models.get(id) {
+            Some(model) if matches!(model.action, Action::Spawn(_)) => (),
+            Some(model) => {
+                tracing::warn!(action = ?model.action, "cannot delete a built-in shortcut");
+                return;
             }
+            None => return,
+        }
 
-            ShortcutMessage::CancelReplace => self.replace_dialog = None,
+        let model = self.shortcut_models.remove(id);
 
-            ShortcutMessage::DeleteBinding(id) => {
-                if let Some(short_id) = self.shortcut_context {
-                    if let Some(model) = self.shortcut_models.get_mut(short_id) {
-                        let shortcut = model.bindings.remove(id);
-                        if shortcut.is_default {
-                            self.config_add(Action::Disable, shortcut.binding.clone());
-                        } else {
-                            // if last keybind deleted, clear shortcut context
-                            if model.bindings.is_empty() {
-                                self.shortcut_context = None;
-                            }
-                            self.config_remove(&shortcut.binding);
-                        }
+        // The freed slot may be reused by another shortcut, so nothing may keep referring to it.
+        self.sorted.retain(|&sorted| sorted != id);
+        if self.shortcut_context == Some(id) {
+            self.shortcut_context = None;
+        }
 
-                        self.on_enter();
-                    }
-                }
+        let previous = self.shortcuts_config();
+        let result = self.config_edit(|shortcuts| {
+            for (_, shortcut) in &model.bindings {
+                shortcuts.0.remove(&shortcut.binding);
             }
+        });
 
-            ShortcutMessage::DeleteShortcut(id) => {
-                let model = self.shortcut_models.remove(id);
-                for (_, shortcut) in model.bindings {
-                    self.config_remove(&shortcut.binding);
-                    self.on_enter();
-                }
+        match result {
+            Ok(()) => {
+                self.edited(previous, &[model.action]);
             }
+            Err(why) => self.write_failed(&why),
+        }
+    }
 
-            ShortcutMessage::EditBinding(id, enable) => {
-                if let Some(short_id) = self.shortcut_context {
-                    if let Some(model) = self.shortcut_models.get_mut(short_id) {
-                        if let Some(shortcut) = model.bindings.get_mut(id) {
+    /// Buttons for undoing and redoing changes, if there are any.
+    pub(super) fn history_buttons(&self) -> Option<Element<'_, ShortcutMessage>> {
+        if self.undo.is_empty() && self.redo.is_empty() {
+            return None;
+        }
+
+        let undo_button = (!self.undo.is_empty())
+            .then(|| widget::button::standard(fl!("undo")).on_press(ShortcutMessage::Undo));
+
+        let redo_button = (!self.redo.is_empty())
+            .then(|| widget::button::standard(fl!("redo")).on_press(ShortcutMessage::Redo));
+
+        widget::row::with_capacity(2)
+            .spacing(theme::active().cosmic().spacing.space_xs)
+            .push_maybe(undo_button)
+            .push_maybe(redo_button)
+            .apply(Element::from)
+            .apply(Some)
+    }
+
+    /// Records a change so that it can be undone, and refreshes the shortcuts which it affected.
+    ///
+    /// Besides the edited actions, these include the defaults which a changed binding overrode,
+    /// since they become active again when it is removed.
+    fn edited(&mut self, previous: Shortcuts, edited: &[Action]) {
+        let current = self.shortcuts_config();
+        let mut actions = changed_actions(&self.defaults, &previous, &current);
+        for action in edited {
+            if !actions.contains(action) {
+                actions.push(action.clone());
+            }
+        }
+
+        if !same_shortcuts(&previous, &current) {
+            self.push_undo(previous);
+        }
+
+        self.refresh(&actions);
+    }
+
+    /// Records the custom shortcuts from before a change, so that it can be undone.
+    pub(super) fn push_undo(&mut self, previous: Shortcuts) {
+        self.undo.push(previous);
+        self.redo.clear();
+    }
+
+    /// Stops watching the config and drops the state of the page, when it is left.
+    pub(super) fn on_clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        if let Some(cancel) = self.watcher.take() {
+            _ = cancel.send(());
+        }
+
+        self.clear_results();
+    }
+
+    /// Drops the listed shortcuts and the lookup, while the config is still watched.
+    pub(super) fn clear_results(&mut self) {
+        self.search.clear();
+        self.lookup.clear();
+        self.lookup_result = None;
+        self.shortcut_context = None;
+        self.shortcut_models.clear();
+        self.shortcut_models.shrink_to_fit();
+        self.sorted.clear();
+    }
+
+    /// Orders the shortcuts by their descriptions.
+    ///
+    /// Shortcuts with the same description are ordered by their action and bindings, since
+    /// the order of the models depends on the config they were read from.
+    pub(super) fn sort(&mut self) {
+        let collator = collator();
+        let models = &self.shortcut_models;
+
+        let tiebreak = |model: &ShortcutModel| {
+            let mut bindings = model
+                .bindings
+                .iter()
+                .map(|(_, shortcut)| shortcut.binding.to_string())
+                .collect::<Vec<_>>();
+
+            bindings.sort();
+            (format!("{:?}", model.action), bindings)
+        };
+
+        self.sorted = models.iter().map(|(id, _)| id).collect();
+        self.sorted.sort_by_cached_key(|&id| tiebreak(&models[id]));
+
+        // The sort is stable, so shortcuts with the same description keep the tiebreak order.
+        self.sorted.sort_by(|&a, &b| {
+            let (a, b) = (&models[a], &models[b]);
+            match collator.as_ref() {
+                Some(collator) => collator.compare(&a.description, &b.description),
+                None => a.description.cmp(&b.description),
+            }
+        });
+    }
+
+    /// Gets the custom configuration for keyboard shortcuts.
+    ///
+    /// Writes still queued by any page are included, so edits never apply over stale shortcuts.
+    pub(super) fn shortcuts_config(&self) -> Shortcuts {
+        self.writer
+            .pending()
+            .unwrap_or_else(|| self.loaded.lock().unwrap().clone())
+    }
+
+    /// Gets the system configuration for keyboard shortcuts.
+    pub(super) fn shortcuts_system_config(&self) -> Shortcuts {
+        let mut shortcuts = self.defaults.clone();
+        shortcuts.0.extend(self.shortcuts_config().0);
+        shortcuts
+    }
+
+    /// Writes a new configuration to the keyboard shortcuts config file.
+    pub(super) fn shortcuts_config_set(
+        &self,
+        shortcuts: Shortcuts,
+    ) -> Result<(), cosmic_config::Error> {
+        // An unreadable config is kept as a backup, rather than overwritten without a copy.
+        if self.read_error.is_some() {
+            self.set_aside_unreadable()?;
+        }
+
+        self.loaded.lock().unwrap().clone_from(&shortcuts);
+
+        // Failures of queued writes are reported later by `ShortcutMessage::WriteFailed`.
+        let Err(shortcuts) = self.writer.queue(shortcuts) else {
+            return Ok(());
+        };
+
+        self.config.set("custom", shortcuts).inspect_err(|why| {
+            tracing::error!(?why, "failed to write shortcuts config");
+        })
+    }
+
+    /// Moves the custom shortcuts config into the backups if it still cannot be read.
+    fn set_aside_unreadable(&self) -> Result<(), cosmic_config::Error> {
+        match self.config.get::<Shortcuts>("custom") {
+            Ok(_) => return Ok(()),
+            Err(cosmic_config::Error::GetKey(_, why)) if why.kind() == io::ErrorKind::NotFound => {
+                return Ok(());
+            }
+            Err(_) => (),
+        }
+
+        let path = super::migrate::custom_path().ok_or_else(|| {
+            cosmic_config::Error::Io(io::Error::new(
+                io::ErrorKind::NotFound,
+                "unable to locate the custom shortcuts config",
+            ))
+        })?;
+
+        let backup = super::backup::set_aside(&path).map_err(cosmic_config::Error::Io)?;
+        tracing::info!(?backup, "set aside the unreadable custom shortcuts");
+
+        Ok(())
+    }
+
+    /// Check if the config was changed other than by this page, dropping the undo history if so.
+    pub(super) fn config_changed(&mut self) -> bool {
+        // Changes made by this page are reloaded as they are made,
+        // and the config is outdated while they are still being written.
+        if self.writer.pending().is_some() {
+            return false;
+        }
+
+        let stored = self.config.get::<Shortcuts>("custom").unwrap_or_default();
+        let defaults = self.config.get::<Shortcuts>("defaults").unwrap_or_default();
+        if same_shortcuts(&stored, &self.shortcuts_config())
+            && same_shortcuts(&defaults, &self.defaults)
+        {
+            return false;
+        }
+
+        // The undo history no longer applies to a config changed elsewhere.
+        self.undo.clear();
+        self.redo.clear();
+        true
+    }
+
+    /// Moves the unreadable config into the backups, so that the shortcuts start from the
+    /// defaults. Returns whether the shortcuts should be reloaded.
+    pub(super) fn recover(&mut self) -> bool {
+        self.recovery_dialog = false;
+
+        let Some(path) = super::migrate::custom_path() else {
+            return false;
+        };
+
+        match super::backup::set_aside(&path) {
+            Ok(backup) => {
+                tracing::info!(?backup, "set aside the unreadable custom shortcuts");
+                true
+            }
+
+            Err(why) => {
+                tracing::error!(?why, "failed to set aside the custom shortcuts");
+                self.write_error = Some(why.to_string());
+                false
+            }
+        }
+    }
+
+    /// Displays a failure to write the shortcuts config to the user.
+    ///
+    /// The listed shortcuts are refreshed so that they reflect what is actually stored,
+    /// which keeps search results that were not built from the page's actions.
+    pub(super) fn write_failed(&mut self, why: &impl std::fmt::Display) {
+        self.write_error = Some(why.to_string());
+
+        let actions = self
+            .shortcut_models
+            .iter()
+            .map(|(_, model)| model.action.clone())
+            .collect::<Vec<_>>();
+
+        self.refresh(&actions);
+    }
+
+    #[allow(clippy::too_many_lines)]
+    pub(super) fn update(&mut self, message: ShortcutMessage) -> Task<crate::app::Message> {
+        match message {
+            ShortcutMessage::AddKeybinding => {
+                // The button is only shown in the context drawer, which requires a shortcut.
+                let Some(model) = self
+                    .shortcut_context
+                    .and_then(|short_id| self.shortcut_models.get_mut(short_id))
+                else {
+                    tracing::warn!("cannot add a keybinding without a shortcut in context");
+                    return Task::none();
+                };
+
+                // A model has at most one input for a new binding, which is focused
+                // instead of creating another.
+                let unset = model
+                    .bindings
+                    .iter()
+                    .filter(|(_, shortcut)| !shortcut.binding.is_set())
+                    .map(|(key, _)| key)
+                    .collect::<Vec<_>>();
+
+                if let Some((&first, rest)) = unset.split_first() {
+                    for &key in rest {
+                        model.bindings.remove(key);
+                    }
+
+                    let shortcut = &mut model.bindings[first];
+                    shortcut.editing = true;
+
+                    return widget::text_input::focus(shortcut.id.clone());
+                }
+
+                if model.bindings.len() >= MAX_BINDINGS {
+                    tracing::warn!("a shortcut can have at most {MAX_BINDINGS} bindings");
+                    return Task::none();
+                }
+
+                // Create a new input and focus it.
+                let id = widget::Id::unique();
+                model.bindings.insert(ShortcutBinding {
+                    id: id.clone(),
+                    binding: Binding::default(),
+                    input: String::new(),
+                    editing: true,
+                    is_default: false,
+                });
+
+                return widget::text_input::focus(id);
+            }
+
+            ShortcutMessage::ApplyReplace => {
+                if let Some((id, new_binding, replaced, _)) = self.replace_dialog.take() {
+                    let context_action = self
+                        .shortcut_context
+                        .and_then(|short_id| self.shortcut_models.get(short_id))
+                        .map(|model| model.action.clone());
+
+                    // The binding may have been taken by another shortcut while the dialog was open.
+                    if let Some(action) = self.config_contains(&new_binding).filter(|action| {
+                        *action != replaced && Some(action) != context_action.as_ref()
+                    }) {
+                        let action_str = action_name(&action, &new_binding);
+                        self.replace_dialog = Some((id, new_binding, action, action_str));
+                        return Task::none();
+                    }
+
+                    if let Some(short_id) = self.shortcut_context {
+                        let previous = self.shortcuts_config();
+
+                        // Clear any binding that matches this in the current model
+                        for (_, model) in &mut self.shortcut_models {
+                            if let Some(id) = model
+                                .bindings
+                                .iter()
+                                .find(|(_, shortcut)| shortcut.binding == new_binding)
+                                .map(|(id, _)| id)
+                            {
+                                model.bindings.remove(id);
+                                break;
+                            }
+                        }
+
+                        // Update the current model and save the binding to disk.
+                        let mut actions = vec![replaced];
+                        if let Some(model) = self.shortcut_models.get_mut(short_id) {
+                            actions.push(model.action.clone());
+
+                            if let Some(shortcut) = model.bindings.get_mut(id) {
+                                let prev_binding = shortcut.binding.clone();
+
+                                shortcut.binding = new_binding.clone();
+                                shortcut.input.clear();
+                                shortcut.editing = false;
+
+                                // Remove the conflict saved on disk, and the binding being replaced.
+                                let action = model.action.clone();
+                                let result = self.config_edit(|shortcuts| {
+                                    shortcuts.0.remove(&prev_binding);
+                                    insert_custom(&self.defaults, shortcuts, new_binding, action);
+                                });
+
+                                if let Err(why) = result {
+                                    self.write_failed(&why);
+                                    return Task::none();
+                                }
+                            }
+                        }
+
+                        self.edited(previous, &actions);
+                    }
+                }
+            }
+
+            ShortcutMessage::SuggestBinding(id, binding) => {
+                if let Some(shortcut) = self
+                    .shortcut_context
+                    .and_then(|short_id| self.shortcut_models.get_mut(short_id))
+                    .and_then(|model| model.bindings.get_mut(id))
+                {
+                    shortcut.input = binding.to_string();
+                    return widget::text_input::focus(shortcut.id.clone());
+                }
+            }
+
+            ShortcutMessage::UseSuggestion(binding) => {
+                let Some((id, ..)) = self.replace_dialog.take() else {
+                    return Task::none();
+                };
+
+                if let Some(shortcut) = self
+                    .shortcut_context
+                    .and_then(|short_id| self.shortcut_models.get_mut(short_id))
+                    .and_then(|model| model.bindings.get_mut(id))
+                {
+                    shortcut.input = binding.to_string();
+                }
+
+                // Submitted as if typed, so that it is checked for conflicts again.
+                return self.update(ShortcutMessage::SubmitBinding(id));
+            }
+
+            ShortcutMessage::CancelReplace => {
+                let Some((id, ..)) = self.replace_dialog.take() else {
+                    return Task::none();
+                };
+
+                // The rejected input is reverted to the binding it was replacing, and focused
+                // again so that another can be typed straight away.
+                if let Some(shortcut) = self
+                    .shortcut_context
+                    .and_then(|short_id| self.shortcut_models.get_mut(short_id))
+                    .and_then(|model| model.bindings.get_mut(id))
+                {
+                    shortcut.input = if shortcut.binding.is_set() {
+                        shortcut.binding.to_string()
+                    } else {
+                        String::new()
+                    };
+                    shortcut.editing = true;
+
+                    return Task::batch(vec![
+                        widget::text_input::focus(shortcut.id.clone()),
+                        widget::text_input::select_all(shortcut.id.clone()),
+                    ]);
+                }
+            }
+
+            ShortcutMessage::ConfigChanged => {
+                if self.config_changed() {
+                    self.reload();
+                }
+            }
+
+            ShortcutMessage::RecoverApply => {
+                if self.recover() {
+                    self.reload();
+                }
+            }
+
+            ShortcutMessage::RecoverCancel => self.recovery_dialog = false,
+
+            ShortcutMessage::SetLogoName(logo) => {
+                self.label.logo = logo;
+                store_preference(LOGO_NAME_KEY, logo);
+            }
+
+            ShortcutMessage::SetModifierOrder(order) => {
+                self.label.order = order;
+                store_preference(MODIFIER_ORDER_KEY, order);
+            }
+
+            ShortcutMessage::ShowRecovery => self.recovery_dialog = true,
+
+            ShortcutMessage::CopyBinding(id) => {
+                if let Some(short_id) = self.shortcut_context {
+                    if let Some(model) = self.shortcut_models.get(short_id) {
+                        if let Some(shortcut) = model.bindings.get(id) {
+                            return cosmic::iced::clipboard::write(shortcut.binding.to_string());
+                        }
+                    }
+                }
+            }
+
+            ShortcutMessage::DeleteBinding(id) => {
+                if let Some(short_id) = self.shortcut_context {
+                    let previous = self.shortcuts_config();
+
+                    if let Some(model) = self.shortcut_models.get_mut(short_id) {
+                        let action = model.action.clone();
+                        let shortcut = model.bindings.remove(id);
+                        let result = if shortcut.is_default {
+                            self.config_add(Action::Disable, shortcut.binding.clone())
+                        } else {
+                            // if last keybind deleted, clear shortcut context
+                            if model.bindings.is_empty() {
+                                self.shortcut_context = None;
+                            }
+                            self.config_remove(&shortcut.binding)
+                        };
+
+                        match result {
+                            Ok(()) => {
+                                self.edited(previous, &[action]);
+                            }
+                            Err(why) => self.write_failed(&why),
+                        }
+                    }
+                }
+            }
+
+            ShortcutMessage::DeleteShortcut(id) => {
+                // Deleting every binding of a custom shortcut at once is confirmed first.
+                let bindings = self.shortcut_models.get(id).map_or(0, |m| m.bindings.len());
+                if self.custom && bindings > 1 {
+                    self.delete_dialog = Some(id);
+                } else {
+                    self.delete_shortcut(id);
+                }
+            }
+
+            ShortcutMessage::ConfirmDelete => {
+                if let Some(id) = self.delete_dialog.take() {
+                    self.delete_shortcut(id);
+                }
+            }
+
+            ShortcutMessage::CancelDelete => self.delete_dialog = None,
+
+            ShortcutMessage::DismissWriteError => self.write_error = None,
+
+            ShortcutMessage::WriteFailed(why) => self.write_failed(&why),
+
+            ShortcutMessage::DuplicateShortcut(_) => (),
+
+            ShortcutMessage::ClearInput(id) => {
+                if let Some(short_id) = self.shortcut_context {
+                    if let Some(model) = self.shortcut_models.get_mut(short_id) {
+                        if let Some(shortcut) = model.bindings.get_mut(id) {
+                            shortcut.editing = false;
+                            shortcut.input = if shortcut.binding.is_set() {
+                                shortcut.binding.to_string()
+                            } else {
+                                String::new()
+                            };
+                        }
+
+                        model.prune_empty();
+                    }
+                }
+            }
+
+            ShortcutMessage::EditBinding(id, enable) => {
+                if let Some(short_id) = self.shortcut_context {
+                    if let Some(model) = self.shortcut_models.get_mut(short_id) {
+                        if let Some(shortcut) = model.bindings.get_mut(id) {
                             shortcut.editing = enable;
                             if enable {
                                 shortcut.input = shortcut.binding.to_string();
                                 return widget::text_input::select_all(shortcut.id.clone());
                             }
                         }
+
+                        model.prune_empty();
                     }
                 }
             }
 
+            ShortcutMessage::EditCommand(enable) => {
+                let command = self
+                    .shortcut_context
+                    .and_then(|id| self.shortcut_models.get(id))
+                    .and_then(|model| match &model.action {
+                        Action::Spawn(command) => Some(command.clone()),
+                        _ => None,
+                    });
+
+                if !enable {
+                    self.command_input = None;
+                    self.command_warning = command
+                        .as_deref()
+                        .and_then(super::command::missing_executable);
+                } else if let Some(command) = command {
+                    self.command_input = Some(command);
+                    return widget::text_input::select_all(self.command_id.clone());
+                }
+            }
+
+            ShortcutMessage::EditDescription(enable) => {
+                if !enable {
+                    self.description_input = None;
+                } else if let Some(model) = self
+                    .shortcut_context
+                    .and_then(|id| self.shortcut_models.get(id))
+                {
+                    let description = model
+                        .bindings
+                        .iter()
+                        .find_map(|(_, shortcut)| shortcut.binding.description.clone())
+                        .unwrap_or_default();
+
+                    self.description_input = Some(description);
+                    return widget::text_input::select_all(self.description_id.clone());
+                }
+            }
+
+            ShortcutMessage::InputDescription(text) => {
+                if let Some(input) = self.description_input.as_mut() {
+                    *input = text;
+                }
+            }
+
+            // Relabels every binding of the custom shortcut in context.
+            ShortcutMessage::SubmitDescription => {
+                let Some(input) = self.description_input.take() else {
+                    return Task::none();
+                };
+
+                let Some(action) = self
+                    .shortcut_context
+                    .and_then(|id| self.shortcut_models.get(id))
+                    .map(|model| model.action.clone())
+                    .filter(|action| matches!(action, Action::Spawn(_)))
+                else {
+                    return Task::none();
+                };
+
+                // An empty label falls back to displaying the command.
+                let description = Some(input.trim().to_owned()).filter(|d| !d.is_empty());
+
+                // The description is part of the binding, which is the key of the config.
+                let mut shortcuts = self.shortcuts_config();
+                shortcuts.0 = shortcuts
+                    .0
+                    .into_iter()
+                    .map(|(mut binding, binding_action)| {
+                        if binding_action == action {
+                            binding.description = description.clone();
+                        }
+
+                        (binding, binding_action)
+                    })
+                    .collect();
+
+                if let Err(why) = self.shortcuts_config_set(shortcuts) {
+                    self.write_failed(&why);
+                    return Task::none();
+                }
+
+                self.reload();
+
+                // Retitle the context drawer with the new label.
+                if let Some(model) = self.shortcut_context.map(|id| &self.shortcut_models[id]) {
+                    return cosmic::task::message(crate::app::Message::OpenContextDrawer(
+                        self.entity,
+                        model.description.clone().into(),
+                    ));
+                }
+            }
+
+            ShortcutMessage::InputCommand(text) => {
+                self.command_warning = super::command::missing_executable(&text);
+                if let Some(input) = self.command_input.as_mut() {
+                    *input = text;
+                }
+            }
+
+            // Moves every binding of the custom shortcut in context onto the new command.
+            ShortcutMessage::SubmitCommand => {
+                let Some(input) = self.command_input.take() else {
+                    return Task::none();
+                };
+
+                let command = input.trim();
+                if command.is_empty() {
+                    return Task::none();
+                }
+
+                let new_action = Action::Spawn(command.to_owned());
+
+                let old_action = match self
+                    .shortcut_context
+                    .and_then(|id| self.shortcut_models.get(id))
+                {
+                    Some(model)
+                        if matches!(model.action, Action::Spawn(_))
+                            && model.action != new_action =>
+                    {
+                        model.action.clone()
+                    }
+                    _ => return Task::none(),
+                };
+
+                // Actions are values of the config, so the bindings and their
+                // descriptions are preserved by replacing them in place.
+                let mut shortcuts = self.shortcuts_config();
+                for action in shortcuts.0.values_mut() {
+                    if *action == old_action {
+                        *action = new_action.clone();
+                    }
+                }
+
+                if let Err(why) = self.shortcuts_config_set(shortcuts) {
+                    self.write_failed(&why);
+                    return Task::none();
+                }
+
+                // The model follows its new action, so that its state is kept on reload.
+                if let Some(model) = self
+                    .shortcut_context
+                    .and_then(|id| self.shortcut_models.get_mut(id))
+                {
+                    model.action = new_action;
+                }
+
+                self.reload();
+
+                self.command_warning = super::command::missing_executable(command);
+            }
+
             ShortcutMessage::InputBinding(id, text) => {
                 if let Some(short_id) = self.shortcut_context {
                     if let Some(model) = self.shortcut_models.get_mut(short_id) {
@@ -369,40 +1830,189 @@ impl Model {
                 }
             }
 
+            ShortcutMessage::Redo => {
+                if let Some(next) = self.redo.pop() {
+                    let current = self.shortcuts_config();
+                    let actions = changed_actions(&self.defaults, &current, &next);
+                    match self.shortcuts_config_set(next) {
+                        Ok(()) => {
+                            self.undo.push(current);
+                            self.refresh(&actions);
+                        }
+                        Err(why) => self.write_failed(&why),
+                    }
+                }
+            }
+
+            ShortcutMessage::ResetBinding(id) => {
+                let Some(model) = self
+                    .shortcut_context
+                    .and_then(|short_id| self.shortcut_models.get(short_id))
+                else {
+                    return Task::none();
+                };
+
+                let Some(shortcut) = model.bindings.get(id) else {
+                    return Task::none();
+                };
+
+                let Some(default) = model.replaced_default(&shortcut.binding).cloned() else {
+                    return Task::none();
+                };
+
+                let action = model.action.clone();
+                let previous = self.shortcuts_config();
+                let result = self.config_edit(|shortcuts| {
+                    shortcuts.0.remove(&shortcut.binding);
+                    shortcuts.0.remove(&default);
+                });
+
+                match result {
+                    Ok(()) => {
+                        self.edited(previous, &[action]);
+                    }
+                    Err(why) => self.write_failed(&why),
+                }
+            }
+
             // Removes all bindings from the active shortcut context, and reloads the shortcuts model.
             ShortcutMessage::ResetBindings => {
-                if let Some(short_id) = self.shortcut_context {
-                    if let Some(model) = self.shortcut_models.get(short_id) {
-                        for (_, shortcut) in &model.bindings {
-                            self.config_remove(&shortcut.binding);
-                        }
+                let Some(model) = self
+                    .shortcut_context
+                    .and_then(|short_id| self.shortcut_models.get(short_id))
+                else {
+                    return Task::none();
+                };
+
+                self.backup();
+
+                let action = model.action.clone();
+                let previous = self.shortcuts_config();
+                match self.reset_actions(std::slice::from_ref(&action)) {
+                    Ok(()) => {
+                        self.edited(previous, &[action]);
+                    }
+                    Err(why) => self.write_failed(&why),
+                }
+            }
 
-                        if let Ok(defaults) = self.config.get::<Shortcuts>("defaults") {
-                            for (binding, action) in defaults.0 {
-                                if action == model.action {
-                                    self.config_remove(&binding);
-                                }
-                            }
-                        }
+            ShortcutMessage::ResetCategory => self.reset_category_dialog = true,
+
+            ShortcutMessage::ResetCategoryCancel => self.reset_category_dialog = false,
+
+            ShortcutMessage::ResetCategoryApply => {
+                self.reset_category_dialog = false;
+
+                let actions = self
+                    .shortcut_models
+                    .iter()
+                    .map(|(_, model)| model.action.clone())
+                    .collect::<Vec<_>>();
+
+                self.backup();
+
+                let previous = self.shortcuts_config();
+                match self.reset_actions(&actions) {
+                    Ok(()) => {
+                        self.edited(previous, &actions);
+                    }
+                    Err(why) => self.write_failed(&why),
+                }
+            }
+
+            ShortcutMessage::DisableBindings => {
+                let Some(model) = self
+                    .shortcut_context
+                    .and_then(|short_id| self.shortcut_models.get(short_id))
+                else {
+                    return Task::none();
+                };
+
+                let action = model.action.clone();
+                let previous = self.shortcuts_config();
+                let result = self.config_edit(|shortcuts| {
+                    for (_, shortcut) in model.bindings.iter().filter(|(_, s)| !s.is_default) {
+                        shortcuts.0.remove(&shortcut.binding);
                     }
 
-                    self.on_enter();
+                    for (binding, _) in self.defaults.0.iter().filter(|(_, a)| **a == action) {
+                        shortcuts.0.remove(binding);
+                        shortcuts.0.insert(binding.clone(), Action::Disable);
+                    }
+                });
+
+                match result {
+                    Ok(()) => {
+                        self.edited(previous, &[action]);
+                    }
+                    Err(why) => self.write_failed(&why),
                 }
             }
 
+            ShortcutMessage::LookupInput(text) => {
+                self.lookup = text;
+                self.lookup_result = None;
+            }
+
+            // Opens the shortcut which owns the typed key combination.
+            ShortcutMessage::LookupSubmit => {
+                let binding = match super::binding::parse(&self.lookup) {
+                    Ok(binding) if binding.is_set() => binding,
+                    _ => {
+                        self.lookup_result = Some(fl!("shortcut-lookup", "invalid"));
+                        return Task::none();
+                    }
+                };
+
+                let owner = self
+                    .shortcut_models
+                    .iter()
+                    .find(|(_, model)| {
+                        model
+                            .bindings
+                            .iter()
+                            .any(|(_, shortcut)| shortcut.binding == binding)
+                    })
+                    .map(|(id, model)| (id, model.description.clone()));
+
+                if let Some((id, description)) = owner {
+                    self.lookup_result = None;
+                    return self.update(ShortcutMessage::ShowShortcut(id, description));
+                }
+
+                self.lookup_result = Some(match self.config_contains(&binding) {
+                    Some(action) => fl!(
+                        "shortcut-lookup",
+                        "elsewhere",
+                        name = super::localize_custom_action(&action, &binding)
+                    ),
+                    None => fl!("shortcut-lookup", "none"),
+                });
+            }
+
+            ShortcutMessage::Search(input) => self.search = input,
+
             ShortcutMessage::ShowShortcut(id, description) => {
+                if let Some(model) = self.shortcut_models.get_mut(id) {
+                    model.prune_empty();
+                }
+
                 self.shortcut_context = Some(id);
                 self.replace_dialog = None;
+                self.command_input = None;
+                self.description_input = None;
+                self.command_warning = match self.shortcut_models.get(id).map(|m| &m.action) {
+                    Some(Action::Spawn(command)) => super::command::missing_executable(command),
+                    _ => None,
+                };
 
                 let mut tasks = vec![cosmic::task::message(
                     crate::app::Message::OpenContextDrawer(self.entity, description.into()),
                 )];
 
-                if let Some(model) = self.shortcut_models.get(0) {
-                    if let Some(shortcut) = model.bindings.get(0) {
-                        tasks.push(widget::text_input::focus(shortcut.id.clone()));
-                        tasks.push(widget::text_input::select_all(shortcut.id.clone()));
-                    }
+                if let Some(input) = first_binding_input(&self.shortcut_models, id) {
+                    tasks.push(widget::text_input::focus(input.clone()));
+                    tasks.push(widget::text_input::select_all(input));
                 }
 
                 return Task::batch(tasks);
@@ -414,72 +2024,369 @@ impl Model {
 
                     // Check for conflicts with the new binding.
                     if let Some(model) = self.shortcut_models.get_mut(short_id) {
+                        // The existing binding is focused, rather than adding it twice.
+                        if let Some(existing) = model.duplicate_of(id).map(|s| s.id.clone()) {
+                            if let Some(shortcut) = model.bindings.get_mut(id) {
+                                shortcut.input.clear();
+                                shortcut.editing = false;
+                            }
+
+                            model.prune_empty();
+                            return widget::text_input::focus(existing);
+                        }
+
                         if let Some(shortcut) = model.bindings.get_mut(id) {
-                            match Binding::from_str(&shortcut.input) {
+                            match super::binding::parse(&shortcut.input) {
                                 Ok(new_binding) => {
+                                    // Typed bindings are stored alike however they were written.
+                                    let new_binding = super::binding::canonical(&new_binding);
                                     if !new_binding.is_set() {
                                         shortcut.input.clear();
+
+                                        // An empty row for a new binding is discarded.
+                                        if !shortcut.binding.is_set() {
+                                            shortcut.editing = false;
+                                            model.prune_empty();
+                                        }
+
                                         return Task::none();
                                     }
                                     if let Some(action) = self.config_contains(&new_binding) {
-                                        let action_str = if let Action::Spawn(_) = &action {
-                                            super::localize_custom_action(&action, &new_binding)
-                                        } else {
-                                            super::localize_action(&action)
-                                        };
+                                        let action_str = action_name(&action, &new_binding);
                                         self.replace_dialog =
                                             Some((id, new_binding, action, action_str));
                                         return Task::none();
                                     }
 
-                                    apply_binding = Some(new_binding);
-                                }
+                                    apply_binding = Some(new_binding);
+                                }
+
+                                Err(why) => {
+                                    tracing::error!(why, "keybinding input invalid");
+                                }
+                            }
+                        }
+                    }
+
+                    // Apply if no conflict was found.
+                    if let Some(new_binding) = apply_binding {
+                        let previous = self.shortcuts_config();
+
+                        if let Some(model) = self.shortcut_models.get_mut(short_id) {
+                            if let Some(shortcut) = model.bindings.get_mut(id) {
+                                let prev_binding = shortcut.binding.clone();
+
+                                shortcut.binding = new_binding.clone();
+                                shortcut.input.clear();
+                                shortcut.editing = false;
+
+                                let action = model.action.clone();
+                                let result = self.config_edit(|shortcuts| {
+                                    shortcuts.0.remove(&prev_binding);
+                                    insert_custom(
+                                        &self.defaults,
+                                        shortcuts,
+                                        new_binding,
+                                        action.clone(),
+                                    );
+                                });
+
+                                match result {
+                                    Ok(()) => {
+                                        self.edited(previous, &[action]);
+                                    }
+                                    Err(why) => self.write_failed(&why),
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            ShortcutMessage::ToggleCompare(enable) => {
+                self.compare = enable;
+                store_preference(COMPARE_KEY, enable);
+            }
+
+            ShortcutMessage::ToggleCustomFilter(enable) => self.custom_only = enable,
+
+            ShortcutMessage::ToggleDefault(binding, enable) => {
+                let actions = self
+                    .defaults
+                    .0
+                    .get(&binding)
+                    .cloned()
+                    .into_iter()
+                    .collect::<Vec<_>>();
+                let previous = self.shortcuts_config();
+                let result = if enable {
+                    self.config_remove(&binding)
+                } else {
+                    self.config_add(Action::Disable, binding)
+                };
+
+                match result {
+                    Ok(()) => {
+                        self.edited(previous, &actions);
+                    }
+                    Err(why) => self.write_failed(&why),
+                }
+            }
+
+            ShortcutMessage::ToggleExpanded(id) => {
+                if let Some(model) = self.shortcut_models.get_mut(id) {
+                    model.expanded = !model.expanded;
+                }
+            }
+
+            ShortcutMessage::ToggleModifiedFilter(enable) => self.modified_only = enable,
+
+            ShortcutMessage::ToggleModifierFilter(key, enable) => {
+                *self.modifier_filter.get_mut(key) = enable;
+            }
+
+            ShortcutMessage::ToggleSymbols(enable) => {
+                self.label.symbols = enable;
+                store_preference(SYMBOLS_KEY, enable);
+            }
+
+            ShortcutMessage::Undo => {
+                if let Some(previous) = self.undo.pop() {
+                    let current = self.shortcuts_config();
+                    let actions = changed_actions(&self.defaults, &current, &previous);
+                    match self.shortcuts_config_set(previous) {
+                        Ok(()) => {
+                            self.redo.push(current);
+                            self.refresh(&actions);
+                        }
+                        Err(why) => self.write_failed(&why),
+                    }
+                }
+            }
+        }
+
+        Task::none()
+    }
+
+    pub(super) fn view(&self) -> Element<ShortcutMessage> {
+        let search = self.search.to_lowercase();
+
+        self.sorted
+            .iter()
+            .filter_map(|&id| Some((id, self.shortcut_models.get(id)?)))
+            .filter(|(_, shortcut)| self.is_visible(shortcut, &search))
+            .map(|(id, shortcut)| {
+                if self.compare {
+                    comparison_item(self.label, id, shortcut, &self.defaults)
+                } else {
+                    shortcut_item(self.custom, self.label, id, shortcut)
+                }
+            })
+            .fold(widget::list_column(), widget::ListColumn::add)
+            .into()
+    }
+}
+
+/// Stores a display preference in the config of the settings app.
+fn store_preference<T: serde::Serialize>(key: &str, value: T) {
+    match cosmic_config::Config::new(SETTINGS_CONFIG, 1) {
+        Ok(context) => {
+            if let Err(why) = context.set(key, value) {
+                tracing::error!(?why, key, "failed to store shortcut display preference");
+            }
+        }
+        Err(why) => tracing::error!(?why, "failed to get settings config"),
+    }
+}
+
+/// Assigns a binding to an action in the custom shortcuts.
+///
+/// A binding which the defaults already assign to the action is left out, so that the custom
+/// shortcuts only hold changes from the defaults.
+fn insert_custom(defaults: &Shortcuts, custom: &mut Shortcuts, binding: Binding, action: Action) {
+    // The key is removed first, since inserting would keep the description of the old key.
+    custom.0.remove(&binding);
+
+    let is_default = defaults
+        .0
+        .get_key_value(&binding)
+        .is_some_and(|(default, default_action)| {
+            *default_action == action && default.description == binding.description
+        });
+
+    if !is_default {
+        custom.0.insert(binding, action);
+    }
+}
+
+/// Actions whose bindings differ between two versions of the custom shortcuts.
+///
+/// The default action of a changed binding is included, since the binding overrode it.
+fn changed_actions(defaults: &Shortcuts, a: &Shortcuts, b: &Shortcuts) -> Vec<Action> {
+    let mut actions = Vec::new();
+
+    for (binding, action) in a.0.iter().chain(&b.0) {
+        let unchanged = match (a.0.get_key_value(binding), b.0.get_key_value(binding)) {
+            (Some((a_binding, a_action)), Some((b_binding, b_action))) => {
+                a_action == b_action && a_binding.description == b_binding.description
+            }
+            _ => false,
+        };
+
+        if unchanged {
+            continue;
+        }
+
+        for changed in std::iter::once(action).chain(defaults.0.get(binding)) {
+            if !actions.contains(changed) {
+                actions.push(changed.clone());
+            }
+        }
+    }
+
+    actions
+}
+
+/// Check if two sets of shortcuts are identical, including the descriptions of their bindings.
+fn same_shortcuts(a: &Shortcuts, b: &Shortcuts) -> bool {
+    a.0.len() == b.0.len()
+        && a.0.iter().all(|(binding, action)| {
+            b.0.get_key_value(binding)
+                .is_some_and(|(other, other_action)| {
+                    other_action == action && other.description == binding.description
+                })
+        })
+}
 
-                                Err(why) => {
-                                    tracing::error!(why, "keybinding input invalid");
-                                }
-                            }
-                        }
-                    }
+/// Text of a binding for display, with modifiers and keys localized, and modifiers
+/// optionally replaced by their symbols and reordered, as chosen by the style.
+///
+/// The text form is always used for editing, since it is what the binding is parsed from.
+pub fn binding_label(binding: &Binding, style: LabelStyle) -> String {
+    let text = binding.to_string();
+    let mut modifiers = Vec::new();
+    let mut rest = text.as_str();
+
+    'modifiers: loop {
+        for &(modifier, symbol) in MODIFIER_SYMBOLS {
+            let Some(remainder) = rest
+                .strip_prefix(modifier)
+                .and_then(|remainder| remainder.strip_prefix('+'))
+            else {
+                continue;
+            };
 
-                    // Apply if no conflict was found.
-                    if let Some(new_binding) = apply_binding {
-                        if let Some(model) = self.shortcut_models.get_mut(short_id) {
-                            if let Some(shortcut) = model.bindings.get_mut(id) {
-                                let prev_binding = shortcut.binding.clone();
+            modifiers.push((modifier, symbol));
+            rest = remainder;
+            continue 'modifiers;
+        }
 
-                                shortcut.binding = new_binding.clone();
-                                shortcut.input.clear();
-                                shortcut.editing = false;
+        break;
+    }
 
-                                let action = model.action.clone();
-                                self.config_remove(&prev_binding);
-                                self.config_add(action, new_binding);
-                                self.on_enter();
-                            }
-                        }
-                    }
-                }
-            }
+    modifiers.sort_by_key(|(modifier, _)| style.order.position(modifier));
+
+    let mut label = String::new();
+    for (modifier, symbol) in modifiers {
+        if style.symbols {
+            label.push_str(symbol);
+        } else {
+            label.push_str(&modifier_name(modifier, style));
+            label.push('+');
         }
+    }
 
-        Task::none()
+    label.push_str(&key_name(rest));
+    label
+}
+
+/// Asks to confirm a reset of shortcuts to their defaults.
+pub(super) fn reset_dialog<'a, Message: Clone + 'static>(
+    label: String,
+    title: String,
+    body: String,
+    apply: Message,
+    cancel: Message,
+) -> Element<'a, Message> {
+    widget::dialog()
+        .title(title)
+        .icon(icon::from_name("dialog-warning").size(64))
+        .body(body)
+        .primary_action(button::destructive(label).on_press(apply))
+        .secondary_action(button::standard(fl!("cancel")).on_press(cancel))
+        .into()
+}
+
+/// Shows the keysym names of a binding on hover, when its label displays them differently.
+fn keysym_tooltip<'a, Message: 'static>(
+    content: impl Into<Element<'a, Message>>,
+    binding: &Binding,
+    style: LabelStyle,
+) -> Element<'a, Message> {
+    let keysyms = binding.to_string();
+
+    if !binding.is_set() || binding_label(binding, style) == keysyms {
+        return content.into();
     }
 
-    pub(super) fn view(&self) -> Element<ShortcutMessage> {
-        self.shortcut_models
-            .iter()
-            .map(|(id, shortcut)| shortcut_item(self.custom, id, shortcut))
-            .fold(widget::list_column(), widget::ListColumn::add)
-            .into()
+    widget::tooltip(content, text::body(keysyms), widget::tooltip::Position::Top).into()
+}
+
+/// Localized name of a modifier, with the logo modifier named as chosen by the style.
+fn modifier_name(modifier: &str, style: LabelStyle) -> Cow<'_, str> {
+    match (modifier, style.logo) {
+        ("Super", LogoName::Meta) => Cow::Owned(fl!("modifier-key", "meta")),
+        _ => key_name(modifier),
     }
 }
 
-fn context_drawer(
-    shortcuts: &Slab<ShortcutModel>,
-    id: usize,
-    show_action: bool,
-) -> Element<ShortcutMessage> {
+/// Localized name of a modifier or key, falling back to its keysym name.
+fn key_name(key: &str) -> Cow<'_, str> {
+    Cow::Owned(match key {
+        "Super" => fl!("modifier-key", "super"),
+        "Ctrl" => fl!("modifier-key", "ctrl"),
+        "Alt" => fl!("modifier-key", "alt"),
+        "Shift" => fl!("modifier-key", "shift"),
+        "BackSpace" => fl!("key-name", "backspace"),
+        "Caps_Lock" => fl!("key-name", "caps-lock"),
+        "Delete" => fl!("key-name", "delete"),
+        "Down" => fl!("key-name", "down"),
+        "End" => fl!("key-name", "end"),
+        "Escape" => fl!("key-name", "escape"),
+        "Home" => fl!("key-name", "home"),
+        "Insert" => fl!("key-name", "insert"),
+        "Left" => fl!("key-name", "left"),
+        "Menu" => fl!("key-name", "menu"),
+        // Distinct from the main Enter key, which bindings are easily mistaken for.
+        "KP_Enter" => fl!("key-name", "numpad-enter"),
+        "Next" | "Page_Down" => fl!("key-name", "page-down"),
+        "Prior" | "Page_Up" => fl!("key-name", "page-up"),
+        "Print" => fl!("key-name", "print"),
+        "Return" => fl!("key-name", "return"),
+        "Right" => fl!("key-name", "right"),
+        "space" => fl!("key-name", "space"),
+        "Tab" => fl!("key-name", "tab"),
+        "Up" => fl!("key-name", "up"),
+        "XF86AudioLowerVolume" => fl!("key-name", "volume-down"),
+        "XF86AudioMute" => fl!("key-name", "mute"),
+        "XF86AudioNext" => fl!("key-name", "media-next"),
+        "XF86AudioPlay" => fl!("key-name", "media-play"),
+        "XF86AudioPrev" => fl!("key-name", "media-previous"),
+        "XF86AudioRaiseVolume" => fl!("key-name", "volume-up"),
+        "XF86MonBrightnessDown" => fl!("key-name", "brightness-down"),
+        "XF86MonBrightnessUp" => fl!("key-name", "brightness-up"),
+        // Punctuation is written by its character, which needs no translation.
+        "comma" => return Cow::Borrowed(","),
+        "equal" => return Cow::Borrowed("="),
+        "minus" => return Cow::Borrowed("-"),
+        "period" => return Cow::Borrowed("."),
+        "plus" => return Cow::Borrowed("+"),
+        "slash" => return Cow::Borrowed("/"),
+        _ => return Cow::Borrowed(key),
+    })
+}
+
+fn context_drawer(state: &Model, id: usize) -> Element<ShortcutMessage> {
     let cosmic::cosmic_theme::Spacing {
         space_xxs,
         space_xs,
@@ -487,50 +2394,225 @@ fn context_drawer(
         ..
     } = theme::active().cosmic().spacing;
 
-    let model = &shortcuts[id];
+    let model = &state.shortcut_models[id];
+    let show_action = state.custom;
+    let rtl = is_right_to_left();
+    let config = state.shortcuts_system_config();
+
+    let action = show_action.then(|| -> Element<ShortcutMessage> {
+        if let Action::Spawn(command) = &model.action {
+            let description = widget::editable_input(
+                fl!("shortcut-name"),
+                state
+                    .description_input
+                    .as_deref()
+                    .unwrap_or(model.description.as_str()),
+                state.description_input.is_some(),
+                ShortcutMessage::EditDescription,
+            )
+            .on_input(ShortcutMessage::InputDescription)
+            .on_submit(ShortcutMessage::SubmitDescription)
+            .padding([0, space_xs])
+            .id(state.description_id.clone());
+
+            let input = widget::editable_input(
+                "",
+                state.command_input.as_deref().unwrap_or(command.as_str()),
+                state.command_input.is_some(),
+                ShortcutMessage::EditCommand,
+            )
+            .on_input(ShortcutMessage::InputCommand)
+            .on_submit(ShortcutMessage::SubmitCommand)
+            .padding([0, space_xs])
+            .id(state.command_id.clone());
 
-    let action = show_action.then(|| {
-        let description = if let Action::Spawn(task) = &model.action {
-            Cow::Borrowed(task.as_str())
-        } else {
-            Cow::Owned(super::localize_action(&model.action))
-        };
+            let warning = state.command_warning.as_ref().map(|executable| {
+                text::caption(fl!("command-not-found", executable = executable.as_str()))
+            });
+
+            return widget::column::with_capacity(3)
+                .spacing(space_xxs)
+                .push(description)
+                .push(input)
+                .push_maybe(warning)
+                .into();
+        }
 
-        text::body(description)
+        text::body(&model.description).into()
     });
 
-    let bindings = model.bindings.iter().enumerate().fold(
+    // The drawer cannot be scrolled to a widget, so inputs for new bindings are placed last,
+    // next to the button which added them, to keep a newly focused input in view.
+    let (set, new): (Vec<_>, Vec<_>) = model
+        .bindings
+        .iter()
+        .partition(|(_, shortcut)| shortcut.binding.is_set());
+
+    let bindings = set.into_iter().chain(new).fold(
         widget::list_column().spacing(space_xxs),
-        |section, (_, (bind_id, shortcut))| {
+        |section, (bind_id, shortcut)| {
             let text: Cow<'_, str> = if !shortcut.editing && shortcut.binding.is_set() {
-                Cow::Owned(shortcut.binding.to_string())
+                Cow::Owned(binding_label(&shortcut.binding, state.label))
             } else {
                 Cow::Borrowed(&shortcut.input)
             };
 
-            let input = widget::editable_input("", text, shortcut.editing, move |enable| {
+            // Input which cannot be parsed is not submitted, rather than being discarded.
+            let invalid = shortcut.editing
+                && !shortcut.input.trim().is_empty()
+                && super::binding::parse(&shortcut.input).is_err();
+
+            let mut input = widget::editable_input("", text, shortcut.editing, move |enable| {
                 ShortcutMessage::EditBinding(bind_id, enable)
             })
             .select_on_focus(true)
             .on_input(move |text| ShortcutMessage::InputBinding(bind_id, text))
-            .on_submit(ShortcutMessage::SubmitBinding(bind_id))
             .padding([0, space_xs])
-            .id(shortcut.id.clone())
-            .into();
+            .id(shortcut.id.clone());
 
-            let delete_button = widget::button::icon(icon::from_name("edit-delete-symbolic"))
-                .on_press(ShortcutMessage::DeleteBinding(bind_id))
-                .into();
+            if !invalid {
+                input = input.on_submit(ShortcutMessage::SubmitBinding(bind_id));
+            }
+
+            let input = if shortcut.editing {
+                input.into()
+            } else {
+                keysym_tooltip(input, &shortcut.binding, state.label)
+            };
+
+            let clear_button = shortcut.editing.then(|| {
+                widget::button::icon(icon::from_name("edit-undo-symbolic"))
+                    .tooltip(fl!("shortcut-button", "clear"))
+                    .on_press(ShortcutMessage::ClearInput(bind_id))
+                    .into()
+            });
+
+            // Offered when the typed binding is taken, to fill in a similar one which is free.
+            let suggest_button = shortcut
+                .editing
+                .then(|| super::binding::parse(&shortcut.input).ok())
+                .flatten()
+                .filter(|binding| {
+                    config
+                        .0
+                        .get(binding)
+                        .is_some_and(|action| *action != Action::Disable)
+                })
+                .and_then(|binding| next_free_binding(&binding, &config))
+                .map(|suggestion| {
+                    widget::button::icon(icon::from_name("edit-find-replace-symbolic"))
+                        .tooltip(fl!("shortcut-button", "suggest"))
+                        .on_press(ShortcutMessage::SuggestBinding(bind_id, suggestion))
+                        .into()
+                });
+
+            let reset_button = (!shortcut.is_default
+                && !shortcut.editing
+                && shortcut.binding.is_set()
+                && !model.disabled.is_empty())
+            .then(|| {
+                widget::button::icon(icon::from_name("document-revert-symbolic"))
+                    .tooltip(fl!("shortcut-button", "reset"))
+                    .on_press(ShortcutMessage::ResetBinding(bind_id))
+                    .into()
+            });
+
+            let default_badge = (shortcut.is_default && shortcut.binding.is_set())
+                .then(|| text::caption(fl!("default-binding")).into());
+
+            let copy_button = shortcut.binding.is_set().then(|| {
+                widget::button::icon(icon::from_name("edit-copy-symbolic"))
+                    .tooltip(fl!("shortcut-button", "copy"))
+                    .on_press(ShortcutMessage::CopyBinding(bind_id))
+                    .into()
+            });
+
+            // Default bindings are disabled rather than deleted, so that they can be enabled again.
+            let delete_button = if shortcut.is_default && shortcut.binding.is_set() {
+                let binding = shortcut.binding.clone();
+                widget::toggler(true)
+                    .on_toggle(move |enable| {
+                        ShortcutMessage::ToggleDefault(binding.clone(), enable)
+                    })
+                    .into()
+            } else {
+                widget::button::icon(icon::from_name("edit-delete-symbolic"))
+                    .tooltip(fl!("shortcut-button", "delete-binding"))
+                    .on_press(ShortcutMessage::DeleteBinding(bind_id))
+                    .into()
+            };
 
-            let flex_control =
-                settings::item_row(vec![input, delete_button]).align_y(Alignment::Center);
+            let flex_control = settings::item_row(mirror(
+                std::iter::once(input)
+                    .chain(clear_button)
+                    .chain(suggest_button)
+                    .chain(default_badge)
+                    .chain(reset_button)
+                    .chain(copy_button)
+                    .chain(std::iter::once(delete_button))
+                    .collect(),
+                rtl,
+            ))
+            .align_y(Alignment::Center);
+
+            let invalid_warning = invalid.then(|| text::caption(fl!("invalid-binding")));
+
+            let reserved_warning = is_reserved(&shortcut.binding)
+                .then(|| text::caption(fl!("reserved-shortcut-warning")));
+
+            // Only a new binding is checked, while it is being entered.
+            let entered = shortcut
+                .editing
+                .then(|| super::binding::parse(&shortcut.input).ok())
+                .flatten();
+
+            let shadow_warning = entered
+                .and_then(|binding| shadowing_binding(&config, &binding, &model.action))
+                .map(|(other, action)| {
+                    text::caption(fl!(
+                        "shadowed-binding-warning",
+                        binding = binding_label(&other, state.label),
+                        name = action_name(&action, &other)
+                    ))
+                });
 
-            section.add(flex_control)
+            section.add(
+                widget::column::with_capacity(3)
+                    .spacing(space_xxs)
+                    .push(flex_control)
+                    .push_maybe(invalid_warning)
+                    .push_maybe(reserved_warning)
+                    .push_maybe(shadow_warning),
+            )
         },
     );
 
-    // TODO: Detect when it is necessary
-    let reset_keybinding_button = if show_action {
+    let bindings = model.disabled.iter().fold(bindings, |section, binding| {
+        let disabled = binding.clone();
+
+        section.add(
+            settings::item_row(mirror(
+                vec![
+                    // Greyed out, since the binding has no effect until it is re-enabled.
+                    text::body(binding_label(binding, state.label))
+                        .class(theme::Text::Color(color!(128, 128, 128)))
+                        .width(Length::Fill)
+                        .into(),
+                    text::caption(fl!("default-binding", "disabled")).into(),
+                    widget::toggler(false)
+                        .on_toggle(move |enable| {
+                            ShortcutMessage::ToggleDefault(disabled.clone(), enable)
+                        })
+                        .into(),
+                ],
+                rtl,
+            ))
+            .align_y(Alignment::Center),
+        )
+    });
+
+    // Only offered when resetting would change the bindings.
+    let reset_keybinding_button = if show_action || model.modified == 0 {
         None
     } else {
         let button = widget::button::standard(fl!("reset-to-default"))
@@ -538,43 +2620,320 @@ fn context_drawer(
         Some(button)
     };
 
-    let add_keybinding_button =
-        widget::button::standard(fl!("add-keybinding")).on_press(ShortcutMessage::AddKeybinding);
+    // Custom shortcuts have no default bindings to disable, and are deleted instead.
+    let disable_bindings_button =
+        (!show_action && model.bindings.iter().any(|(_, s)| s.binding.is_set())).then(|| {
+            widget::button::standard(fl!("disable-all-bindings"))
+                .on_press(ShortcutMessage::DisableBindings)
+        });
+
+    let at_limit = model.bindings.len() >= MAX_BINDINGS;
+    let limit_warning = at_limit.then(|| text::caption(fl!("binding-limit", count = MAX_BINDINGS)));
+
+    let add_keybinding_button = widget::button::standard(fl!("add-keybinding"))
+        .on_press_maybe((!at_limit).then_some(ShortcutMessage::AddKeybinding));
+
+    let buttons = state
+        .history_buttons()
+        .into_iter()
+        .chain(disable_bindings_button.map(Element::from))
+        .chain(reset_keybinding_button.map(Element::from))
+        .chain(std::iter::once(add_keybinding_button.into()))
+        .collect();
 
-    let button_container = widget::row::with_capacity(2)
-        .push_maybe(reset_keybinding_button)
-        .push(add_keybinding_button)
+    let button_container = widget::row::with_children(mirror(buttons, rtl))
         .spacing(space_xs)
         .apply(widget::container)
         .width(Length::Fill)
-        .align_x(Alignment::End);
+        .align_x(if rtl {
+            Alignment::Start
+        } else {
+            Alignment::End
+        });
+
+    // What resetting would restore, shown for reference while editing the bindings.
+    let defaults = (!show_action).then(|| {
+        let mut labels = state
+            .defaults
+            .0
+            .iter()
+            .filter(|(_, action)| **action == model.action)
+            .map(|(binding, _)| binding_label(binding, state.label))
+            .collect::<Vec<_>>();
 
-    widget::column::with_capacity(if show_action { 3 } else { 2 })
+        labels.sort();
+
+        let labels = if labels.is_empty() {
+            fl!("not-set")
+        } else {
+            labels.join(", ")
+        };
+
+        widget::list_column().add(settings::item(fl!("default-binding"), text::body(labels)))
+    });
+
+    widget::column::with_capacity(5)
         .spacing(space_l)
         .push_maybe(action)
+        .push_maybe(defaults)
         .push(bindings)
         .push(button_container)
+        .push_maybe(limit_warning)
+        .into()
+}
+
+/// A collator for the active language, used to sort localized descriptions.
+fn collator() -> Option<Collator> {
+    let language = crate::localize::LANGUAGE_LOADER.current_language();
+    let locale = Locale::from_str(&language.to_string()).ok()?;
+    Collator::try_new(&locale.into(), CollatorOptions::new()).ok()
+}
+
+/// Whether the active language is written from right to left.
+fn is_right_to_left() -> bool {
+    let language = crate::localize::LANGUAGE_LOADER.current_language();
+    Locale::from_str(&language.to_string())
+        .is_ok_and(|locale| LocaleDirectionality::new().is_right_to_left(&locale.id))
+}
+
+/// Reverses the order of a row's children in right-to-left layouts.
+fn mirror<Message>(
+    mut children: Vec<Element<'_, Message>>,
+    rtl: bool,
+) -> Vec<Element<'_, Message>> {
+    if rtl {
+        children.reverse();
+    }
+
+    children
+}
+
+/// The input of the first binding of a shortcut, which is focused when its context drawer opens.
+fn first_binding_input(models: &Slab<ShortcutModel>, id: usize) -> Option<widget::Id> {
+    let model = models.get(id)?;
+    let (_, shortcut) = model.bindings.iter().next()?;
+    Some(shortcut.id.clone())
+}
+
+/// Check if a binding may be intercepted by the system before it reaches the shortcuts config.
+fn is_reserved(binding: &Binding) -> bool {
+    binding.is_set() && RESERVED.contains(binding)
+}
+
+/// Name of the action which a binding is assigned to, for describing a conflict.
+fn action_name(action: &Action, binding: &Binding) -> String {
+    if let Action::Spawn(_) = action {
+        super::localize_custom_action(action, binding)
+    } else {
+        super::localize_action(action)
+    }
+}
+
+/// A binding of the same key with more modifiers, which no shortcut uses yet.
+///
+/// Bindings with the fewest added modifiers are preferred, and those which the system may
+/// intercept are skipped.
+fn next_free_binding(base: &Binding, system: &Shortcuts) -> Option<Binding> {
+    if !base.is_set() || base.key.is_none() {
+        return None;
+    }
+
+    let mut masks = (1u8..16).collect::<Vec<_>>();
+    masks.sort_by_key(|mask| mask.count_ones());
+
+    masks
+        .into_iter()
+        .map(|mask| {
+            let mut candidate = base.clone();
+            candidate.description = None;
+            candidate.modifiers.logo |= mask & 1 != 0;
+            candidate.modifiers.ctrl |= mask & 2 != 0;
+            candidate.modifiers.alt |= mask & 4 != 0;
+            candidate.modifiers.shift |= mask & 8 != 0;
+            candidate
+        })
+        .filter(|candidate| candidate != base && !is_reserved(candidate))
+        .find(|candidate| {
+            system
+                .0
+                .get(candidate)
+                .filter(|action| **action != Action::Disable)
+                .is_none()
+        })
+}
+
+/// A binding of another action with the same key, whose modifiers are a strict subset or
+/// superset of the binding's, such that one may shadow or be mistaken for the other.
+fn shadowing_binding(
+    config: &Shortcuts,
+    binding: &Binding,
+    action: &Action,
+) -> Option<(Binding, Action)> {
+    // Check if every modifier of `a` is also held by `b`.
+    let within = |a: &Binding, b: &Binding| {
+        (!a.modifiers.logo || b.modifiers.logo)
+            && (!a.modifiers.ctrl || b.modifiers.ctrl)
+            && (!a.modifiers.alt || b.modifiers.alt)
+            && (!a.modifiers.shift || b.modifiers.shift)
+    };
+
+    if !binding.is_set() || binding.key.is_none() {
+        return None;
+    }
+
+    config
+        .0
+        .iter()
+        .filter(|(_, other_action)| **other_action != Action::Disable && *other_action != action)
+        .find(|(other, _)| {
+            other.key == binding.key && within(other, binding) != within(binding, other)
+        })
+        .map(|(other, other_action)| ((*other).clone(), other_action.clone()))
+}
+
+/// A shortcut with its default bindings alongside its current bindings, where defaults
+/// which were removed are greyed out, and bindings which were added are highlighted.
+fn comparison_item<'a>(
+    style: LabelStyle,
+    id: usize,
+    data: &'a ShortcutModel,
+    defaults: &Shortcuts,
+) -> Element<'a, ShortcutMessage> {
+    let current = data
+        .bindings
+        .iter()
+        .map(|(_, shortcut)| &shortcut.binding)
+        .filter(|binding| binding.is_set())
+        .collect::<Vec<_>>();
+
+    let mut default_bindings = defaults
+        .0
+        .iter()
+        .filter(|(_, action)| **action == data.action)
+        .map(|(binding, _)| binding)
+        .collect::<Vec<_>>();
+
+    default_bindings.sort_by_cached_key(|binding| binding.to_string());
+
+    let column = |heading: String, bindings: Vec<Element<'a, ShortcutMessage>>| {
+        let bindings = if bindings.is_empty() {
+            vec![text::body(fl!("not-set")).into()]
+        } else {
+            bindings
+        };
+
+        widget::column::with_capacity(2)
+            .push(text::caption(heading))
+            .push(widget::column::with_children(bindings))
+            .width(Length::Fill)
+    };
+
+    let removed = default_bindings
+        .iter()
+        .map(|binding| {
+            let label = text::body(binding_label(binding, style));
+            if current.contains(binding) {
+                label.into()
+            } else {
+                label
+                    .class(theme::Text::Color(color!(128, 128, 128)))
+                    .into()
+            }
+        })
+        .collect();
+
+    let added = current
+        .iter()
+        .map(|binding| {
+            let label = text::body(binding_label(binding, style));
+            if default_bindings.contains(binding) {
+                label.into()
+            } else {
+                label.class(theme::Text::Accent).into()
+            }
+        })
+        .collect();
+
+    let control = widget::row::with_capacity(2)
+        .push(column(fl!("shortcut-compare", "default"), removed))
+        .push(column(fl!("shortcut-compare", "current"), added))
+        .spacing(16);
+
+    settings::item::builder(&data.description)
+        .flex_control(control)
+        .spacing(16)
+        .apply(widget::container)
+        .class(theme::Container::List)
+        .apply(widget::button::custom)
+        .class(theme::Button::Transparent)
+        .on_press(ShortcutMessage::ShowShortcut(id, data.description.clone()))
         .into()
 }
 
 /// Display a shortcut as a list item
-fn shortcut_item(custom: bool, id: usize, data: &ShortcutModel) -> Element<ShortcutMessage> {
+fn shortcut_item(
+    custom: bool,
+    style: LabelStyle,
+    id: usize,
+    data: &ShortcutModel,
+) -> Element<ShortcutMessage> {
+    /// Number of bindings shown before the rest are collapsed behind an expander.
+    const COLLAPSED_BINDINGS: usize = 3;
+
     #[derive(Copy, Clone, Debug)]
     enum LocalMessage {
+        Duplicate,
+        Expand,
         Remove,
         Show,
     }
 
-    let bindings = data
+    let set_bindings = data
         .bindings
         .iter()
-        .take(3)
         .filter(|(_, shortcut)| shortcut.binding.is_set())
-        .map(|(_, shortcut)| text::body(shortcut.binding.to_string()).into())
         .collect::<Vec<_>>();
 
+    let hidden = if data.expanded {
+        0
+    } else {
+        set_bindings.len().saturating_sub(COLLAPSED_BINDINGS)
+    };
+
+    let mut bindings = set_bindings
+        .iter()
+        .take(set_bindings.len() - hidden)
+        .map(|(_, shortcut)| {
+            keysym_tooltip(
+                text::body(binding_label(&shortcut.binding, style)),
+                &shortcut.binding,
+                style,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if set_bindings.len() > COLLAPSED_BINDINGS {
+        let label = if data.expanded {
+            fl!("shortcut-bindings", "show-less")
+        } else {
+            fl!("shortcut-bindings", "more", count = hidden)
+        };
+
+        bindings.push(
+            widget::button::link(label)
+                .on_press(LocalMessage::Expand)
+                .into(),
+        );
+    }
+
     let shortcuts: Element<LocalMessage> = if bindings.is_empty() {
-        text::body(fl!("disabled")).into()
+        // Unbound actions differ from those whose default bindings were disabled.
+        text::body(if data.disabled.is_empty() {
+            fl!("not-set")
+        } else {
+            fl!("disabled")
+        })
+        .into()
     } else {
         widget::column::with_children(bindings)
             .align_x(Alignment::End)
@@ -587,12 +2946,18 @@ fn shortcut_item(custom: bool, id: usize, data: &ShortcutModel) -> Element<Short
         Some(text::body(fl!("modified", count = data.modified)))
     };
 
-    let control = widget::row::with_capacity(4)
+    let control = widget::row::with_capacity(5)
         .push_maybe(modified)
         .push(shortcuts)
         .push(icon::from_name("go-next-symbolic").size(16))
+        .push_maybe(custom.then(|| {
+            widget::button::icon(icon::from_name("edit-copy-symbolic"))
+                .tooltip(fl!("shortcut-button", "duplicate"))
+                .on_press(LocalMessage::Duplicate)
+        }))
         .push_maybe(custom.then(|| {
             widget::button::icon(icon::from_name("edit-delete-symbolic"))
+                .tooltip(fl!("shortcut-button", "delete-shortcut"))
                 .on_press(LocalMessage::Remove)
         }))
         .align_y(Alignment::Center)
@@ -608,7 +2973,455 @@ fn shortcut_item(custom: bool, id: usize, data: &ShortcutModel) -> Element<Short
         .on_press(LocalMessage::Show)
         .apply(Element::from)
         .map(move |message| match message {
+            LocalMessage::Duplicate => ShortcutMessage::DuplicateShortcut(id),
+            LocalMessage::Expand => ShortcutMessage::ToggleExpanded(id),
             LocalMessage::Show => ShortcutMessage::ShowShortcut(id, data.description.clone()),
             LocalMessage::Remove => ShortcutMessage::DeleteShortcut(id),
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn focus_selected_shortcut() {
+        let defaults = Shortcuts::default();
+        let mut shortcuts = Shortcuts::default();
+        shortcuts
+            .0
+            .insert(Binding::from_str("Super+q").unwrap(), Action::Close);
+        shortcuts
+            .0
+            .insert(Binding::from_str("Super+m").unwrap(), Action::Maximize);
+
+        let mut models = Slab::new();
+        models.insert(ShortcutModel::new(&defaults, &shortcuts, Action::Close));
+        let selected = models.insert(ShortcutModel::new(&defaults, &shortcuts, Action::Maximize));
+
+        let (_, expected) = models[selected].bindings.iter().next().unwrap();
+        assert_eq!(
+            first_binding_input(&models, selected),
+            Some(expected.id.clone())
+        );
+    }
+
+    #[test]
+    fn modified_count() {
+        let close = Binding::from_str("Super+q").unwrap();
+        let alt_close = Binding::from_str("Alt+F4").unwrap();
+
+        let mut defaults = Shortcuts::default();
+        defaults.0.insert(close.clone(), Action::Close);
+
+        let modified = |custom: Vec<(Binding, Action)>| {
+            let mut shortcuts = defaults.clone();
+            shortcuts.0.extend(custom);
+            ShortcutModel::new(&defaults, &shortcuts, Action::Close).modified
+        };
+
+        assert_eq!(modified(Vec::new()), 0);
+        assert_eq!(modified(vec![(alt_close.clone(), Action::Close)]), 1);
+        assert_eq!(modified(vec![(close.clone(), Action::Disable)]), 1);
+        assert_eq!(modified(vec![(close.clone(), Action::Maximize)]), 1);
+        assert_eq!(
+            modified(vec![
+                (close.clone(), Action::Disable),
+                (alt_close.clone(), Action::Close)
+            ]),
+            1
+        );
+        assert_eq!(
+            modified(vec![
+                (alt_close.clone(), Action::Close),
+                (Binding::from_str("Super+w").unwrap(), Action::Close)
+            ]),
+            2
+        );
+    }
+
+    #[test]
+    fn disabled_default_is_one_modification() {
+        let close = Binding::from_str("Super+q").unwrap();
+        let alt_close = Binding::from_str("Alt+F4").unwrap();
+
+        let mut defaults = Shortcuts::default();
+        defaults.0.insert(close.clone(), Action::Close);
+        defaults.0.insert(alt_close.clone(), Action::Close);
+
+        let mut shortcuts = defaults.clone();
+        shortcuts.0.insert(close.clone(), Action::Disable);
+
+        let model = ShortcutModel::new(&defaults, &shortcuts, Action::Close);
+        assert_eq!(model.modified, 1);
+        assert_eq!(model.disabled, vec![close]);
+        assert_eq!(model.bindings.len(), 1);
+    }
+
+    #[test]
+    fn replaced_default() {
+        let close = Binding::from_str("Super+q").unwrap();
+        let alt_close = Binding::from_str("Alt+F4").unwrap();
+
+        let mut defaults = Shortcuts::default();
+        defaults.0.insert(close.clone(), Action::Close);
+        defaults.0.insert(alt_close.clone(), Action::Close);
+
+        let mut shortcuts = defaults.clone();
+        shortcuts.0.insert(close.clone(), Action::Disable);
+        shortcuts.0.insert(alt_close.clone(), Action::Disable);
+
+        let model = ShortcutModel::new(&defaults, &shortcuts, Action::Close);
+        let replaced = |binding: &str| model.replaced_default(&Binding::from_str(binding).unwrap());
+
+        // The same key is preferred over the same modifiers.
+        assert_eq!(replaced("Ctrl+F4"), Some(&alt_close));
+        assert_eq!(replaced("Super+w"), Some(&close));
+        assert_eq!(replaced("Alt+w"), Some(&alt_close));
+    }
+
+    #[test]
+    fn duplicate_binding() {
+        let defaults = Shortcuts::default();
+        let mut shortcuts = Shortcuts::default();
+        shortcuts
+            .0
+            .insert(Binding::from_str("Super+q").unwrap(), Action::Close);
+
+        let mut model = ShortcutModel::new(&defaults, &shortcuts, Action::Close);
+        let (existing, _) = model.bindings.iter().next().unwrap();
+        let new = model.bindings.insert(ShortcutBinding {
+            id: widget::Id::unique(),
+            binding: Binding::default(),
+            input: String::from("Super Q"),
+            editing: true,
+            is_default: false,
+        });
+
+        assert_eq!(
+            model.duplicate_of(new).map(|s| s.id.clone()),
+            Some(model.bindings[existing].id.clone())
+        );
+
+        model.bindings[new].input = String::from("Super+w");
+        assert!(model.duplicate_of(new).is_none());
+    }
+
+    #[test]
+    fn shadowed_bindings() {
+        let mut config = Shortcuts::default();
+        config
+            .0
+            .insert(Binding::from_str("Super+k").unwrap(), Action::Close);
+        config
+            .0
+            .insert(Binding::from_str("Super+m").unwrap(), Action::Disable);
+
+        let shadowed = |binding: &str| {
+            shadowing_binding(
+                &config,
+                &Binding::from_str(binding).unwrap(),
+                &Action::Maximize,
+            )
+            .map(|(_, action)| action)
+        };
+
+        assert_eq!(shadowed("Super+Shift+k"), Some(Action::Close));
+        assert_eq!(shadowed("k"), Some(Action::Close));
+        assert_eq!(shadowed("Super+k"), None);
+        assert_eq!(shadowed("Ctrl+k"), None);
+        assert_eq!(shadowed("Super+Shift+m"), None);
+    }
+
+    #[test]
+    fn modifier_order() {
+        let binding = Binding::from_str("Super+Ctrl+Shift+t").unwrap();
+        let mut style = LabelStyle {
+            symbols: true,
+            ..LabelStyle::default()
+        };
+
+        assert_eq!(binding_label(&binding, style), "❖⌃⇧t");
+
+        style.order = ModifierOrder([
+            ModifierKey::Shift,
+            ModifierKey::Ctrl,
+            ModifierKey::Alt,
+            ModifierKey::Super,
+        ]);
+        assert_eq!(binding_label(&binding, style), "⇧⌃❖t");
+
+        let order = style.order.move_earlier(ModifierKey::Super);
+        assert_eq!(order.0[2], ModifierKey::Super);
+        // The first modifier cannot be moved any earlier.
+        assert_eq!(order.move_earlier(ModifierKey::Shift), order);
+        assert!(order.is_valid());
+        assert!(!ModifierOrder([ModifierKey::Ctrl; 4]).is_valid());
+    }
+
+    /// A page with the given shortcuts, which neither reads nor writes the user's config.
+    ///
+    /// Writes are queued rather than stored, so they can be inspected.
+    fn fixture(
+        defaults: &Shortcuts,
+        custom: &Shortcuts,
+    ) -> (Model, std::sync::mpsc::Receiver<Shortcuts>) {
+        let (queue, written) = std::sync::mpsc::channel();
+        let config =
+            cosmic_config::Config::system(shortcuts::ID, super::super::migrate::VERSION).unwrap();
+
+        let mut model = Model::new(config, Arc::new(Writer::new(queue)));
+        model.defaults = defaults.clone();
+        *model.loaded.get_mut().unwrap() = custom.clone();
+
+        (model, written)
+    }
+
+    #[test]
+    fn replace_conflicting_binding() {
+        let binding = Binding::from_str("Super+q").unwrap();
+        let defaults = Shortcuts::default();
+        let mut custom = Shortcuts::default();
+        custom.0.insert(binding.clone(), Action::Close);
+
+        let (mut model, written) = fixture(&defaults, &custom);
+
+        model
+            .shortcut_models
+            .insert(ShortcutModel::new(&defaults, &custom, Action::Close));
+        let maximize =
+            model
+                .shortcut_models
+                .insert(ShortcutModel::new(&defaults, &custom, Action::Maximize));
+        model.sort();
+        model.shortcut_context = Some(maximize);
+
+        let id = model.shortcut_models[maximize]
+            .bindings
+            .insert(ShortcutBinding {
+                id: widget::Id::unique(),
+                binding: Binding::default(),
+                input: String::from("Super+q"),
+                editing: true,
+                is_default: false,
+            });
+
+        _ = model.update(ShortcutMessage::SubmitBinding(id));
+
+        let (dialog_id, dialog_binding, replaced, _) = model.replace_dialog.clone().unwrap();
+        assert_eq!(dialog_id, id);
+        assert_eq!(dialog_binding, binding);
+        assert_eq!(replaced, Action::Close);
+        assert!(written.try_recv().is_err());
+
+        _ = model.update(ShortcutMessage::ApplyReplace);
+
+        assert!(model.replace_dialog.is_none());
+        assert_eq!(
+            written.try_recv().unwrap().0.get(&binding),
+            Some(&Action::Maximize)
+        );
+
+        // The listed shortcuts follow the write, as a user of the page would see them.
+        let bound_to = |action: &Action| {
+            model
+                .shortcuts()
+                .any(|(other, bindings)| other == action && bindings.contains(&&binding))
+        };
+        assert!(bound_to(&Action::Maximize));
+        assert!(!bound_to(&Action::Close));
+    }
+
+    #[test]
+    fn removed_override_restores_default() {
+        let binding = Binding::from_str("Super+q").unwrap();
+        let mut defaults = Shortcuts::default();
+        defaults.0.insert(binding.clone(), Action::Close);
+        let mut custom = Shortcuts::default();
+        custom.0.insert(binding.clone(), Action::Maximize);
+
+        let mut shortcuts = defaults.clone();
+        shortcuts.0.extend(custom.0.clone());
+
+        let (mut model, _written) = fixture(&defaults, &custom);
+        model
+            .shortcut_models
+            .insert(ShortcutModel::new(&defaults, &shortcuts, Action::Close));
+        let maximize = model.shortcut_models.insert(ShortcutModel::new(
+            &defaults,
+            &shortcuts,
+            Action::Maximize,
+        ));
+        model.sort();
+        model.shortcut_context = Some(maximize);
+
+        let bound_to = |model: &Model, action: &Action| {
+            model
+                .shortcuts()
+                .any(|(other, bindings)| other == action && bindings.contains(&&binding))
+        };
+        assert!(!bound_to(&model, &Action::Close));
+
+        let id = model.shortcut_models[maximize]
+            .bindings
+            .iter()
+            .find(|(_, shortcut)| shortcut.binding == binding)
+            .map(|(id, _)| id)
+            .unwrap();
+
+        _ = model.update(ShortcutMessage::DeleteBinding(id));
+
+        // The default which the custom binding overrode is listed again.
+        assert!(bound_to(&model, &Action::Close));
+        assert!(!bound_to(&model, &Action::Maximize));
+    }
+
+    #[test]
+    fn replaced_models_keep_state() {
+        let defaults = Shortcuts::default();
+        let mut shortcuts = Shortcuts::default();
+        shortcuts
+            .0
+            .insert(Binding::from_str("Super+m").unwrap(), Action::Maximize);
+
+        let (mut model, _written) = fixture(&defaults, &shortcuts);
+        model
+            .shortcut_models
+            .insert(ShortcutModel::new(&defaults, &shortcuts, Action::Close));
+        let maximize = model.shortcut_models.insert(ShortcutModel::new(
+            &defaults,
+            &shortcuts,
+            Action::Maximize,
+        ));
+        model.shortcut_context = Some(maximize);
+
+        let id = model.shortcut_models[maximize]
+            .bindings
+            .insert(ShortcutBinding {
+                id: widget::Id::unique(),
+                binding: Binding::default(),
+                input: String::from("Super+x"),
+                editing: true,
+                is_default: false,
+            });
+
+        // The shortcut in context is listed at another index by the new models.
+        let mut models = Slab::new();
+        let moved = models.insert(ShortcutModel::new(&defaults, &shortcuts, Action::Maximize));
+        model.replace_models(models);
+
+        assert_eq!(model.shortcut_context, Some(moved));
+        assert_eq!(
+            model.shortcut_models[moved]
+                .bindings
+                .get(id)
+                .map(|shortcut| shortcut.input.as_str()),
+            Some("Super+x")
+        );
+    }
+
+    #[test]
+    fn delete_writes_once() {
+        let binding = Binding::from_str("Super+t").unwrap();
+        let action = Action::Spawn(String::from("cosmic-term"));
+        let defaults = Shortcuts::default();
+        let mut custom = Shortcuts::default();
+        custom.0.insert(binding.clone(), action.clone());
+
+        let (mut model, written) = fixture(&defaults, &custom);
+
+        let id = model
+            .shortcut_models
+            .insert(ShortcutModel::new(&defaults, &custom, action));
+        model.sort();
+
+        _ = model.update(ShortcutMessage::DeleteShortcut(id));
+
+        assert!(written.try_recv().unwrap().0.get(&binding).is_none());
+        assert!(written.try_recv().is_err());
+        assert!(model.shortcut_models.get(id).is_none());
+    }
+
+    #[test]
+    fn free_bindings() {
+        let mut system = Shortcuts::default();
+        system
+            .0
+            .insert(Binding::from_str("Super+t").unwrap(), Action::Maximize);
+        system.0.insert(
+            Binding::from_str("Super+Ctrl+Delete").unwrap(),
+            Action::Close,
+        );
+        system
+            .0
+            .insert(Binding::from_str("Super+Ctrl+t").unwrap(), Action::Disable);
+
+        let next = |base: &str| next_free_binding(&Binding::from_str(base).unwrap(), &system);
+
+        // One modifier is added, and disabled bindings are free.
+        assert_eq!(next("Super+t"), Binding::from_str("Super+Ctrl+t").ok());
+        // Ctrl+Alt+Delete is reserved by the system.
+        assert_eq!(
+            next("Ctrl+Delete"),
+            Binding::from_str("Ctrl+Shift+Delete").ok()
+        );
+    }
+
+    #[test]
+    fn defaults_are_not_stored() {
+        let close = Binding::from_str("Super+q").unwrap();
+        let mut defaults = Shortcuts::default();
+        defaults.0.insert(close.clone(), Action::Close);
+
+        // Enabling a disabled default removes its override.
+        let mut custom = Shortcuts::default();
+        custom.0.insert(close.clone(), Action::Disable);
+        insert_custom(&defaults, &mut custom, close.clone(), Action::Close);
+        assert!(custom.0.is_empty());
+
+        insert_custom(&defaults, &mut custom, close.clone(), Action::Maximize);
+        assert_eq!(custom.0.get(&close), Some(&Action::Maximize));
+    }
+
+    #[test]
+    fn undone_actions() {
+        let close = Binding::from_str("Super+q").unwrap();
+        let maximize = Binding::from_str("Super+m").unwrap();
+
+        let mut defaults = Shortcuts::default();
+        defaults.0.insert(close.clone(), Action::Close);
+
+        let mut before = Shortcuts::default();
+        before.0.insert(maximize.clone(), Action::Maximize);
+
+        let mut after = before.clone();
+        after.0.insert(close, Action::Minimize);
+
+        // Only the actions of the changed binding are refreshed, including its default.
+        let mut actions = changed_actions(&defaults, &after, &before);
+        actions.sort_by_key(|action| format!("{action:?}"));
+        assert_eq!(actions, vec![Action::Close, Action::Minimize]);
+        assert!(changed_actions(&defaults, &before, &before).is_empty());
+    }
+
+    #[test]
+    fn disabled_bindings_do_not_conflict() {
+        let close = Binding::from_str("Super+q").unwrap();
+        let maximize = Binding::from_str("Super+m").unwrap();
+        let unbound = Binding::from_str("Super+u").unwrap();
+
+        let mut defaults = Shortcuts::default();
+        defaults.0.insert(close.clone(), Action::Close);
+        defaults.0.insert(maximize.clone(), Action::Maximize);
+
+        let mut custom = Shortcuts::default();
+        custom.0.insert(close.clone(), Action::Disable);
+        custom.0.insert(unbound.clone(), Action::Disable);
+
+        let (model, _) = fixture(&defaults, &custom);
+
+        assert_eq!(model.config_contains(&close), None);
+        assert_eq!(model.config_contains(&unbound), None);
+        assert_eq!(model.config_contains(&maximize), Some(Action::Maximize));
+    }
+}
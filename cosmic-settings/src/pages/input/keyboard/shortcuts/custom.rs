@@ -1,5 +1,3 @@
-use std::str::FromStr;
-
 use super::{ShortcutBinding, ShortcutMessage, ShortcutModel};
 
 use cosmic::iced::{Alignment, Length};
@@ -66,6 +64,8 @@ struct AddShortcut {
     pub active: bool,
     pub name: String,
     pub task: String,
+    /// Executable of the task which could not be found.
+    pub missing_executable: Option<String>,
     pub keys: Slab<(String, widget::Id, bool)>,
 }
 
@@ -74,6 +74,7 @@ impl AddShortcut {
         self.active = true;
         self.name.clear();
         self.task.clear();
+        self.missing_executable = None;
 
         if self.keys.is_empty() {
             self.keys
@@ -92,6 +93,7 @@ impl Page {
     pub fn update(&mut self, message: Message) -> Task<crate::app::Message> {
         match message {
             Message::TaskInput(text) => {
+                self.add_shortcut.missing_executable = super::command::missing_executable(&text);
                 self.add_shortcut.task = text;
             }
 
@@ -110,7 +112,7 @@ impl Page {
             Message::AddKeybinding => {
                 // If an empty entry exists, focus it instead of creating a new input.
                 for (_, (binding, id, _)) in &mut self.add_shortcut.keys {
-                    if Binding::from_str(binding).is_ok() {
+                    if super::binding::parse(binding).is_ok() {
                         continue;
                     }
 
@@ -144,10 +146,13 @@ impl Page {
                         continue;
                     }
 
-                    let Ok(binding) = Binding::from_str(keys) else {
+                    let Ok(binding) = super::binding::parse(keys) else {
                         return Task::none();
                     };
 
+                    // Typed bindings are stored alike however they were written.
+                    let binding = super::binding::canonical(&binding);
+
                     if !binding.is_set() {
                         return Task::none();
                     }
@@ -162,10 +167,13 @@ impl Page {
                 }
 
                 for binding in addable_bindings {
-                    self.add_shortcut(binding);
+                    if let Err(why) = self.add_shortcut(binding) {
+                        self.model.write_failed(&why);
+                        return Task::none();
+                    }
                 }
 
-                self.model.on_enter();
+                self.model.reload();
             }
 
             Message::EditCombination => {
@@ -185,11 +193,14 @@ impl Page {
 
             Message::ReplaceApply => {
                 if let Some((binding, ..)) = self.replace_dialog.pop() {
-                    self.model.config_remove(&binding);
-                    self.add_shortcut(binding);
+                    if let Err(why) = self.add_shortcut(binding) {
+                        self.replace_dialog.clear();
+                        self.model.write_failed(&why);
+                        return Task::none();
+                    }
 
                     if self.replace_dialog.is_empty() {
-                        self.model.on_enter();
+                        self.model.reload();
                     }
                 }
             }
@@ -197,10 +208,29 @@ impl Page {
             Message::ReplaceCancel => {
                 _ = self.replace_dialog.pop();
                 if self.replace_dialog.is_empty() {
-                    self.model.on_enter();
+                    self.model.reload();
                 }
             }
 
+            Message::Shortcut(ShortcutMessage::DuplicateShortcut(id)) => {
+                let Some(model) = self.model.shortcut_models.get(id) else {
+                    return Task::none();
+                };
+
+                let Action::Spawn(task) = &model.action else {
+                    return Task::none();
+                };
+
+                // Open the add shortcut form with the name and command of the existing shortcut.
+                let (name, task) = (model.description.clone(), task.clone());
+                let open_context = self.update(Message::ShortcutContext);
+                self.add_shortcut.name = name;
+                self.add_shortcut.missing_executable = super::command::missing_executable(&task);
+                self.add_shortcut.task = task;
+
+                return open_context;
+            }
+
             Message::Shortcut(message) => {
                 if let ShortcutMessage::ShowShortcut(..) = message {
                     self.add_shortcut.active = false;
@@ -242,10 +272,19 @@ impl Page {
             .push(widget::text::body(fl!("shortcut-name")))
             .push(name_input);
 
+        let command_warning = self
+            .add_shortcut
+            .missing_executable
+            .as_ref()
+            .map(|executable| {
+                widget::text::caption(fl!("command-not-found", executable = executable.as_str()))
+            });
+
         let command_control = widget::column()
             .spacing(4)
             .push(widget::text::body(fl!("command")))
-            .push(task_input);
+            .push(task_input)
+            .push_maybe(command_warning);
 
         let input_fields = widget::column()
             .spacing(12)
@@ -275,8 +314,12 @@ impl Page {
 
         let controls = widget::list_column().add(input_fields).add(keys).spacing(0);
 
+        // A custom shortcut requires both a name and a command to run.
+        let addable =
+            !self.add_shortcut.name.trim().is_empty() && !self.add_shortcut.task.trim().is_empty();
+
         let add_keybinding_button = widget::button::standard(fl!("add-keybinding"))
-            .on_press(Message::AddShortcut)
+            .on_press_maybe(addable.then_some(Message::AddShortcut))
             .apply(widget::container)
             .width(Length::Fill)
             .align_x(Alignment::End);
@@ -288,11 +331,11 @@ impl Page {
             .into()
     }
 
-    fn add_shortcut(&mut self, mut binding: Binding) {
+    fn add_shortcut(&mut self, mut binding: Binding) -> Result<(), cosmic_config::Error> {
         self.add_shortcut.active = !self.replace_dialog.is_empty();
-        binding.description = Some(self.add_shortcut.name.clone());
-        let new_action = Action::Spawn(self.add_shortcut.task.clone());
-        self.model.config_add(new_action, binding);
+        binding.description = Some(self.add_shortcut.name.trim().to_owned());
+        let new_action = Action::Spawn(self.add_shortcut.task.trim().to_owned());
+        self.model.config_add(new_action, binding)
     }
 }
 
@@ -327,7 +370,7 @@ impl page::Page<crate::pages::Message> for Page {
                 .body(fl!(
                     "replace-shortcut-dialog",
                     "desc",
-                    shortcut = binding.to_string(),
+                    shortcut = super::common::binding_label(binding, self.model.label),
                     name = action_str.clone()
                 ))
                 .primary_action(primary_action)
@@ -357,9 +400,12 @@ impl page::Page<crate::pages::Message> for Page {
 
     fn on_enter(
         &mut self,
-        _sender: tokio::sync::mpsc::Sender<crate::pages::Message>,
+        sender: tokio::sync::mpsc::Sender<crate::pages::Message>,
     ) -> Task<crate::pages::Message> {
         self.model.on_enter();
+        self.model.watch(sender, |message| {
+            crate::pages::Message::CustomShortcuts(Message::Shortcut(message))
+        });
         Task::none()
     }
 
@@ -403,6 +449,8 @@ fn bindings(_defaults: &Shortcuts, keybindings: &Shortcuts) -> Slab<ShortcutMode
                             slab
                         },
                         description,
+                        disabled: Vec::new(),
+                        expanded: false,
                         modified: 0,
                     });
                 }
@@ -420,6 +468,9 @@ fn shortcuts() -> Section<crate::pages::Message> {
     Section::default()
         .descriptions(descriptions)
         .view::<Page>(move |_binder, page, _section| {
+            let filters = (!page.model.shortcut_models.is_empty())
+                .then(|| page.model.filters().map(Message::Shortcut));
+
             let content = if page.model.shortcut_models.is_empty() {
                 widget::settings::section()
                     .add(widget::settings::item_row(vec![widget::text::body(fl!(
@@ -432,13 +483,30 @@ fn shortcuts() -> Section<crate::pages::Message> {
                 page.model.view().map(Message::Shortcut)
             };
 
-            let add_shortcut = widget::button::standard(fl!("custom-shortcuts", "add"))
-                .on_press(Message::ShortcutContext)
+            let history = page
+                .model
+                .history_buttons()
+                .map(|el| el.map(Message::Shortcut));
+
+            let add_shortcut = widget::row::with_capacity(2)
+                .spacing(8)
+                .push_maybe(history)
+                .push(
+                    widget::button::standard(fl!("custom-shortcuts", "add"))
+                        .on_press(Message::ShortcutContext),
+                )
                 .apply(widget::container)
                 .width(Length::Fill)
                 .align_x(Alignment::End);
 
+            let read_warning = page
+                .model
+                .read_warning()
+                .map(|el| el.map(Message::Shortcut));
+
             widget::column()
+                .push_maybe(read_warning)
+                .push_maybe(filters)
                 .push(content)
                 .push(add_shortcut)
                 .spacing(24)
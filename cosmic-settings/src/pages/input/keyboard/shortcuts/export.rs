@@ -0,0 +1,67 @@
+//! File format for sharing custom shortcuts between systems.
+
+use cosmic_settings_config::shortcuts::Shortcuts;
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+/// Version of the exported shortcuts format.
+pub const VERSION: u32 = 1;
+
+/// Exported custom shortcuts.
+///
+/// Only the custom layer is stored so that the file remains portable across COSMIC versions
+/// which ship different default shortcuts.
+///
+/// Actions and bindings are stored in their serialized form rather than as localized text,
+/// so that the file imports the same on systems using other languages.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ShortcutsFile {
+    pub version: u32,
+    pub custom: Shortcuts,
+}
+
+impl ShortcutsFile {
+    pub fn new(custom: Shortcuts) -> Self {
+        Self {
+            version: VERSION,
+            custom,
+        }
+    }
+
+    /// Parses and validates an exported shortcuts file.
+    pub fn from_ron(input: &str) -> Result<Self, String> {
+        let file: Self = ron::de::from_str(input).map_err(|why| why.to_string())?;
+
+        if file.version > VERSION {
+            return Err(fl!("shortcuts-import", "unsupported"));
+        }
+
+        Ok(file)
+    }
+
+    /// Serializes the shortcuts as a pretty-printed RON document.
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, PrettyConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmic_settings_config::shortcuts::Action;
+    use cosmic_settings_config::Binding;
+    use std::str::FromStr;
+
+    #[test]
+    fn actions_are_not_localized() {
+        let binding = Binding::from_str("Super+q").unwrap();
+        let mut custom = Shortcuts::default();
+        custom.0.insert(binding.clone(), Action::Close);
+
+        let exported = ShortcutsFile::new(custom).to_ron().unwrap();
+        assert!(exported.contains("Close"));
+
+        let imported = ShortcutsFile::from_ron(&exported).unwrap();
+        assert_eq!(imported.custom.0.get(&binding), Some(&Action::Close));
+    }
+}
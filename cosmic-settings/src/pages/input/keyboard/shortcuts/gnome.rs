@@ -0,0 +1,257 @@
+//! Conversion of GNOME keybindings into COSMIC shortcuts.
+//!
+//! Accepts the output of `gsettings list-recursively <schema>` and `dconf dump /`.
+
+use cosmic_settings_config::shortcuts::action::{Direction, System as SystemAction};
+use cosmic_settings_config::shortcuts::{Action, Shortcuts};
+use cosmic_settings_config::Binding;
+use itertools::Itertools;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// Schemas which store keybindings for built-in actions.
+const KEYBINDING_SCHEMAS: &[&str] = &[
+    "org.gnome.desktop.wm.keybindings",
+    "org.gnome.mutter.keybindings",
+    "org.gnome.settings-daemon.plugins.media-keys",
+    "org.gnome.shell.keybindings",
+];
+
+/// Shortcuts converted from a GNOME keybindings dump.
+#[derive(Debug, Default)]
+pub struct Imported {
+    pub shortcuts: Shortcuts,
+    /// Keybindings which could not be converted, for reporting to the user.
+    pub skipped: Vec<String>,
+}
+
+/// Fields of a custom GNOME keybinding.
+#[derive(Default)]
+struct CustomKeybinding {
+    binding: Option<String>,
+    command: Option<String>,
+    name: Option<String>,
+}
+
+pub fn parse(input: &str) -> Imported {
+    let mut imported = Imported::default();
+    let mut custom = BTreeMap::<String, CustomKeybinding>::new();
+    let mut section = String::new();
+
+    for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        // dconf dump: `[org/gnome/desktop/wm/keybindings]` followed by `key=value` lines.
+        if let Some(path) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            section = path.trim_matches('/').replace('/', ".");
+            continue;
+        }
+
+        let (schema, key, value) = match line.split_once('=') {
+            Some((key, value)) if !section.is_empty() => (section.as_str(), key, value),
+            // gsettings: `org.gnome.desktop.wm.keybindings close ['<Alt>F4']`
+            _ => {
+                let mut fields = line.splitn(3, ' ');
+                let (Some(schema), Some(key), Some(value)) =
+                    (fields.next(), fields.next(), fields.next())
+                else {
+                    continue;
+                };
+
+                (schema, key, value)
+            }
+        };
+
+        let (key, value) = (key.trim(), value.trim());
+
+        if schema.contains("custom-keybinding") {
+            let entry = custom.entry(schema.to_owned()).or_default();
+            let value = strings(value).next().map(str::to_owned);
+
+            match key {
+                "binding" => entry.binding = value,
+                "command" => entry.command = value,
+                "name" => entry.name = value,
+                _ => (),
+            }
+
+            continue;
+        }
+
+        // Paths to the custom keybindings, which are parsed from their own sections.
+        if !KEYBINDING_SCHEMAS.contains(&schema) || key == "custom-keybindings" {
+            continue;
+        }
+
+        let accelerators = strings(value)
+            .filter(|accel| !accel.is_empty() && *accel != "disabled")
+            .collect::<Vec<_>>();
+
+        if accelerators.is_empty() {
+            continue;
+        }
+
+        let Some(action) = action(key) else {
+            imported
+                .skipped
+                .push(format!("{key}: {}", accelerators.join(", ")));
+            continue;
+        };
+
+        for accel in accelerators {
+            match accelerator(accel) {
+                Some(binding) => {
+                    imported.shortcuts.0.insert(binding, action.clone());
+                }
+                None => imported.skipped.push(format!("{key}: {accel}")),
+            }
+        }
+    }
+
+    for (_, entry) in custom {
+        let name = entry.name.unwrap_or_default();
+
+        let (Some(accel), Some(command)) = (entry.binding, entry.command) else {
+            imported.skipped.push(name);
+            continue;
+        };
+
+        match accelerator(&accel) {
+            Some(mut binding) => {
+                binding.description = (!name.is_empty()).then_some(name);
+                imported.shortcuts.0.insert(binding, Action::Spawn(command));
+            }
+
+            None => imported.skipped.push(format!("{name}: {accel}")),
+        }
+    }
+
+    imported
+}
+
+/// Converts GNOME's accelerator syntax, such as `<Super><Shift>t`, into a binding.
+pub fn accelerator(accel: &str) -> Option<Binding> {
+    let mut rest = accel.trim();
+    let mut modifiers = Vec::new();
+
+    while let Some(stripped) = rest.strip_prefix('<') {
+        let (modifier, remainder) = stripped.split_once('>')?;
+
+        modifiers.push(match modifier.to_ascii_lowercase().as_str() {
+            "super" | "mod4" => "Super",
+            "primary" | "control" | "ctrl" => "Ctrl",
+            "alt" | "mod1" => "Alt",
+            "shift" => "Shift",
+            _ => return None,
+        });
+
+        rest = remainder;
+    }
+
+    if rest.is_empty() {
+        return None;
+    }
+
+    let binding = modifiers.into_iter().chain(Some(rest)).join("+");
+
+    Binding::from_str(&binding).ok().filter(Binding::is_set)
+}
+
+/// Single-quoted strings in a GVariant value, such as `['<Super>Up', '<Super>k']`.
+fn strings(value: &str) -> impl Iterator<Item = &str> {
+    value.split('\'').skip(1).step_by(2)
+}
+
+/// Maps well-known GNOME keybindings onto COSMIC actions.
+fn action(key: &str) -> Option<Action> {
+    if let Some(num) = key.strip_prefix("switch-to-workspace-") {
+        if let Ok(num) = num.parse() {
+            return Some(Action::Workspace(num));
+        }
+    }
+
+    if let Some(num) = key.strip_prefix("move-to-workspace-") {
+        if let Ok(num) = num.parse() {
+            return Some(Action::MoveToWorkspace(num));
+        }
+    }
+
+    Some(match key {
+        "close" => Action::Close,
+        "maximize" | "toggle-maximized" => Action::Maximize,
+        "minimize" => Action::Minimize,
+        "switch-to-workspace-left" | "switch-to-workspace-up" => Action::PreviousWorkspace,
+        "switch-to-workspace-right" | "switch-to-workspace-down" => Action::NextWorkspace,
+        "switch-to-workspace-last" => Action::LastWorkspace,
+        "move-to-workspace-left" | "move-to-workspace-up" => Action::MoveToPreviousWorkspace,
+        "move-to-workspace-right" | "move-to-workspace-down" => Action::MoveToNextWorkspace,
+        "move-to-workspace-last" => Action::MoveToLastWorkspace,
+        "move-to-monitor-down" => Action::MoveToOutput(Direction::Down),
+        "move-to-monitor-left" => Action::MoveToOutput(Direction::Left),
+        "move-to-monitor-right" => Action::MoveToOutput(Direction::Right),
+        "move-to-monitor-up" => Action::MoveToOutput(Direction::Up),
+        "toggle-on-all-workspaces" => Action::ToggleSticky,
+        "switch-windows" | "switch-applications" => Action::System(SystemAction::WindowSwitcher),
+        "switch-windows-backward" | "switch-applications-backward" => {
+            Action::System(SystemAction::WindowSwitcherPrevious)
+        }
+        "toggle-overview" => Action::System(SystemAction::WorkspaceOverview),
+        "toggle-application-view" => Action::System(SystemAction::AppLibrary),
+        "show-screenshot-ui" | "screenshot" => Action::System(SystemAction::Screenshot),
+        "screensaver" => Action::System(SystemAction::LockScreen),
+        "logout" => Action::System(SystemAction::LogOut),
+        "home" => Action::System(SystemAction::HomeFolder),
+        "www" => Action::System(SystemAction::WebBrowser),
+        "terminal" => Action::System(SystemAction::Terminal),
+        "volume-up" => Action::System(SystemAction::VolumeRaise),
+        "volume-down" => Action::System(SystemAction::VolumeLower),
+        "volume-mute" => Action::System(SystemAction::Mute),
+        "mic-mute" => Action::System(SystemAction::MuteMic),
+        "play" => Action::System(SystemAction::PlayPause),
+        "next" => Action::System(SystemAction::PlayNext),
+        "previous" => Action::System(SystemAction::PlayPrev),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accelerator_syntax() {
+        assert_eq!(
+            accelerator("<Super><Shift>t"),
+            Binding::from_str("Super+Shift+t").ok()
+        );
+        assert_eq!(
+            accelerator("<Primary><Alt>Delete"),
+            Binding::from_str("Ctrl+Alt+Delete").ok()
+        );
+        assert_eq!(accelerator("<Hyper>t"), None);
+        assert_eq!(accelerator("<Super>"), None);
+    }
+
+    #[test]
+    fn dconf_dump() {
+        let imported = parse(
+            "[org/gnome/desktop/wm/keybindings]\n\
+             close=['<Super>q']\n\
+             cycle-group=['<Alt>F6']\n\
+             \n\
+             [org/gnome/settings-daemon/plugins/media-keys/custom-keybindings/custom0]\n\
+             binding='<Super>t'\n\
+             command='kgx'\n\
+             name='Terminal'\n",
+        );
+
+        let close = Binding::from_str("Super+q").unwrap();
+        assert_eq!(imported.shortcuts.0.get(&close), Some(&Action::Close));
+
+        let terminal = Binding::from_str("Super+t").unwrap();
+        assert_eq!(
+            imported.shortcuts.0.get(&terminal),
+            Some(&Action::Spawn(String::from("kgx")))
+        );
+
+        assert_eq!(imported.skipped, vec![String::from("cycle-group: <Alt>F6")]);
+    }
+}
@@ -0,0 +1,102 @@
+//! Migration of custom shortcuts which were written in an older binding format.
+
+use cosmic_settings_config::shortcuts::{self, Shortcuts};
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use std::path::PathBuf;
+
+/// Alternative spellings of modifiers, and the modifier which replaces them.
+const MODIFIER_ALIASES: &[(&str, &str)] = &[
+    ("Logo", "Super"),
+    ("Mod4", "Super"),
+    ("Control", "Ctrl"),
+    ("Primary", "Ctrl"),
+    ("Mod1", "Alt"),
+];
+
+static MODIFIERS: Lazy<Regex> = Lazy::new(|| Regex::new(r"modifiers:\s*\[([^\]]*)\]").unwrap());
+
+/// Version of the shortcuts config, as opened by [`shortcuts::context`].
+pub const VERSION: u64 = 1;
+
+/// Path of the custom shortcuts within the config directory.
+///
+/// This is resolved as `cosmic_config` resolves it, from `XDG_CONFIG_HOME` when it is set.
+pub fn custom_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| {
+        dir.join("cosmic")
+            .join(shortcuts::ID)
+            .join(format!("v{VERSION}"))
+            .join("custom")
+    })
+}
+
+/// Reads the custom shortcuts in their legacy format, if they can be migrated.
+pub fn custom() -> Option<Shortcuts> {
+    let input = std::fs::read_to_string(custom_path()?).ok()?;
+    let migrated = migrate(&input)?;
+
+    match ron::from_str::<Shortcuts>(&migrated) {
+        Ok(shortcuts) => Some(shortcuts),
+        Err(why) => {
+            tracing::error!(?why, "failed to migrate the custom shortcuts");
+            None
+        }
+    }
+}
+
+/// Rewrites legacy forms of bindings, returning `None` if nothing was migrated.
+fn migrate(input: &str) -> Option<String> {
+    let mut changed = false;
+
+    let output = MODIFIERS.replace_all(input, |captures: &Captures| {
+        let modifiers = captures[1]
+            .split(',')
+            .map(str::trim)
+            .filter(|modifier| !modifier.is_empty())
+            .map(|modifier| {
+                match MODIFIER_ALIASES
+                    .iter()
+                    .find(|(alias, _)| *alias == modifier)
+                {
+                    Some((alias, replacement)) => {
+                        tracing::info!("migrating shortcut modifier {alias} to {replacement}");
+                        changed = true;
+                        *replacement
+                    }
+                    None => modifier,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        format!("modifiers: [{}]", modifiers.join(", "))
+    });
+
+    changed.then(|| output.into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modifier_aliases() {
+        assert_eq!(
+            migrate(r#"{(modifiers: [Logo, Control], key: "t"): Spawn("kgx")}"#).as_deref(),
+            Some(r#"{(modifiers: [Super, Ctrl], key: "t"): Spawn("kgx")}"#)
+        );
+
+        assert_eq!(
+            migrate("{\n    (modifiers: [Mod4,Mod1], key: \"q\"): Close,\n}").as_deref(),
+            Some("{\n    (modifiers: [Super, Alt], key: \"q\"): Close,\n}")
+        );
+    }
+
+    #[test]
+    fn current_format() {
+        assert_eq!(
+            migrate(r#"{(modifiers: [Super, Shift], key: "Escape"): Terminate}"#),
+            None
+        );
+    }
+}
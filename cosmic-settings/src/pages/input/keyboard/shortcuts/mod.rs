@@ -1,4 +1,12 @@
+mod backup;
+mod binding;
+mod command;
 mod common;
+mod export;
+#[cfg(feature = "ashpd")]
+mod gnome;
+mod migrate;
+mod writer;
 
 pub use common::{Model, ShortcutBinding, ShortcutMessage, ShortcutModel};
 
@@ -9,7 +17,11 @@ pub mod nav;
 pub mod system;
 pub mod tiling;
 
-use cosmic::iced::Length;
+#[cfg(feature = "ashpd")]
+use ashpd::desktop::file_chooser::{FileFilter, SelectedFiles};
+#[cfg(feature = "ashpd")]
+use ashpd::desktop::ResponseError;
+use cosmic::iced::{Alignment, Length};
 use cosmic::widget::{self, icon, settings, text};
 use cosmic::{theme, Apply, Element, Task};
 use cosmic_config::ConfigGet;
@@ -25,9 +37,19 @@ use shortcuts::action::System as SystemAction;
 use slab::Slab;
 use slotmap::{DefaultKey, Key, SecondaryMap, SlotMap};
 use std::io;
+#[cfg(feature = "ashpd")]
+use std::sync::Arc;
 
 pub struct Page {
     entity: page::Entity,
+    #[cfg(feature = "ashpd")]
+    import_dialog: Option<ImportDialog>,
+    /// Error from the last failed export, shown until dismissed.
+    #[cfg(feature = "ashpd")]
+    export_error: Option<String>,
+    reset_dialog: bool,
+    /// Whether a backup of the custom shortcuts can be restored.
+    has_backup: bool,
     modified: Modified,
     search: Search,
     search_model: Model,
@@ -45,6 +67,21 @@ struct Modified {
     custom: u16,
 }
 
+/// Dialogs shown while importing shortcuts from a file.
+#[cfg(feature = "ashpd")]
+enum ImportDialog {
+    /// Ask whether to merge or replace the current custom shortcuts.
+    Choose {
+        shortcuts: Shortcuts,
+        /// Entries of the file which could not be imported.
+        skipped: Vec<String>,
+    },
+    /// Imported bindings which are already used by other actions.
+    Conflicts(Vec<(Binding, Action, String)>),
+    /// The file could not be imported.
+    Error(String),
+}
+
 struct SubPages {
     custom: page::Entity,
     manage_window: page::Entity,
@@ -66,8 +103,53 @@ struct Search {
 #[derive(Clone, Debug)]
 pub enum Message {
     Category(Category),
+    #[cfg(feature = "ashpd")]
+    ExportDismiss,
+    #[cfg(feature = "ashpd")]
+    ExportError(String),
+    #[cfg(feature = "ashpd")]
+    ExportFile(Arc<SelectedFiles>),
+    #[cfg(feature = "ashpd")]
+    ExportSuccess,
+    /// The file chooser was closed without selecting a file.
+    #[cfg(feature = "ashpd")]
+    FileChooserCancelled,
+    #[cfg(feature = "ashpd")]
+    ImportCancel,
+    #[cfg(feature = "ashpd")]
+    ImportConflictApply,
+    #[cfg(feature = "ashpd")]
+    ImportConflictSkip,
+    #[cfg(feature = "ashpd")]
+    ImportError(String),
+    #[cfg(feature = "ashpd")]
+    ImportFile(Arc<SelectedFiles>),
+    #[cfg(feature = "ashpd")]
+    ImportGnomeFile(Arc<SelectedFiles>),
+    #[cfg(feature = "ashpd")]
+    ImportLoaded(Arc<Shortcuts>, Vec<String>),
+    #[cfg(feature = "ashpd")]
+    ImportMerge,
+    #[cfg(feature = "ashpd")]
+    ImportReplace,
+    ResetAll,
+    ResetAllApply,
+    ResetAllCancel,
+    RestoreBackup,
     Search(String),
     SearchShortcut(ShortcutMessage),
+    #[cfg(feature = "ashpd")]
+    StartExport,
+    #[cfg(feature = "ashpd")]
+    StartGnomeImport,
+    #[cfg(feature = "ashpd")]
+    StartImport,
+}
+
+impl From<Message> for crate::app::Message {
+    fn from(message: Message) -> Self {
+        crate::pages::Message::KeyboardShortcuts(message).into()
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -84,6 +166,12 @@ impl Default for Page {
     fn default() -> Self {
         Self {
             entity: page::Entity::default(),
+            #[cfg(feature = "ashpd")]
+            import_dialog: None,
+            #[cfg(feature = "ashpd")]
+            export_error: None,
+            reset_dialog: false,
+            has_backup: false,
             modified: Modified::default(),
             search: Search::default(),
             search_model: Model::default(),
@@ -110,7 +198,10 @@ impl page::Page<crate::pages::Message> for Page {
         &self,
         sections: &mut SlotMap<section::Entity, Section<crate::pages::Message>>,
     ) -> Option<page::Content> {
-        Some(vec![sections.insert(shortcuts())])
+        Some(vec![
+            sections.insert(shortcuts()),
+            sections.insert(display()),
+        ])
     }
 
     fn info(&self) -> page::Info {
@@ -119,6 +210,43 @@ impl page::Page<crate::pages::Message> for Page {
             .description(fl!("keyboard-shortcuts", "desc"))
     }
 
+    fn header_view(&self) -> Option<Element<'_, crate::pages::Message>> {
+        let content =
+            widget::row::with_capacity(6).spacing(theme::active().cosmic().spacing.space_xxs);
+
+        // Imports are undone from here, since they are not made from a shortcut.
+        let content = content.push_maybe(
+            self.search_model
+                .history_buttons()
+                .map(|buttons| buttons.map(Message::SearchShortcut)),
+        );
+
+        #[cfg(feature = "ashpd")]
+        let content = content
+            .push(widget::button::standard(fl!("import")).on_press(Message::StartImport))
+            .push(
+                widget::button::standard(fl!("shortcuts-import", "gnome"))
+                    .on_press(Message::StartGnomeImport),
+            )
+            .push(widget::button::standard(fl!("export")).on_press(Message::StartExport));
+
+        let content = content
+            .push_maybe(self.has_backup.then(|| {
+                widget::button::standard(fl!("restore-shortcuts-backup"))
+                    .on_press(Message::RestoreBackup)
+            }))
+            .push(
+                widget::button::destructive(fl!("reset-all-shortcuts")).on_press(Message::ResetAll),
+            )
+            .apply(widget::container)
+            .width(Length::Fill)
+            .align_x(Alignment::End)
+            .apply(Element::from)
+            .map(crate::pages::Message::KeyboardShortcuts);
+
+        Some(content)
+    }
+
     fn context_drawer(&self) -> Option<Element<'_, crate::pages::Message>> {
         if self.search_model.shortcut_models.is_empty() {
             None
@@ -130,6 +258,50 @@ impl page::Page<crate::pages::Message> for Page {
     }
 
     fn dialog(&self) -> Option<Element<'_, crate::pages::Message>> {
+        if self.reset_dialog {
+            let dialog = common::reset_dialog(
+                fl!("reset-all-shortcuts"),
+                fl!("reset-all-shortcuts", "dialog"),
+                fl!("reset-all-shortcuts", "desc"),
+                Message::ResetAllApply,
+                Message::ResetAllCancel,
+            )
+            .map(crate::pages::Message::KeyboardShortcuts);
+
+            return Some(dialog);
+        }
+
+        #[cfg(feature = "ashpd")]
+        if let Some(dialog) = self.import_dialog() {
+            return Some(dialog.map(crate::pages::Message::KeyboardShortcuts));
+        }
+
+        #[cfg(feature = "ashpd")]
+        if let Some(why) = self.export_error.as_ref() {
+            let dialog = widget::dialog()
+                .title(fl!("shortcuts-export", "error"))
+                .icon(icon::from_name("dialog-error").size(64))
+                .body(why.as_str())
+                .primary_action(
+                    widget::button::standard(fl!("ok")).on_press(Message::ExportDismiss),
+                )
+                .apply(Element::from)
+                .map(crate::pages::Message::KeyboardShortcuts);
+
+            return Some(dialog);
+        }
+
+        // Failures to read or write the config are shown whether or not there are search results.
+        if let Some(dialog) = self
+            .search_model
+            .read_error_dialog()
+            .or_else(|| self.search_model.write_error_dialog())
+        {
+            return Some(dialog.map(|msg| {
+                crate::pages::Message::KeyboardShortcuts(Message::SearchShortcut(msg))
+            }));
+        }
+
         if self.search_model.shortcut_models.is_empty() {
             None
         } else {
@@ -141,63 +313,17 @@ impl page::Page<crate::pages::Message> for Page {
 
     fn on_enter(
         &mut self,
-        _sender: tokio::sync::mpsc::Sender<crate::pages::Message>,
+        sender: tokio::sync::mpsc::Sender<crate::pages::Message>,
     ) -> Task<crate::pages::Message> {
         if self.shortcuts_context.is_none() {
             self.shortcuts_context = cosmic_settings_config::shortcuts::context().ok();
         }
 
-        if let Some(context) = self.shortcuts_context.as_ref() {
-            let mut defaults = context.get::<Shortcuts>("defaults").unwrap_or_default();
-            let custom = context.get::<Shortcuts>("custom").unwrap_or_default();
-
-            for (custom_binding, custom_action) in &custom.0 {
-                // Skip bindings for the super key
-                if custom_binding.is_super() {
-                    continue;
-                }
-
-                // Check if a custom binding overrides a default binding, or is in addition to it.
-                match defaults.0.get(custom_binding) {
-                    Some(default_action) if default_action == custom_action => continue,
-                    _ => (),
-                }
-
-                match action_category(custom_action) {
-                    Some(Category::ManageWindow) => self.modified.manage_windows += 1,
-                    Some(Category::MoveWindow) => self.modified.move_windows += 1,
-                    Some(Category::Nav) => self.modified.nav += 1,
-                    Some(Category::System) => self.modified.system += 1,
-                    Some(Category::WindowTiling) => self.modified.window_tiling += 1,
-                    None | Some(Category::Custom) => (),
-                }
-            }
-
-            // Check if default bindings are missing
-            for (binding, action) in &defaults.0 {
-                if binding.is_super() {
-                    continue;
-                }
-
-                match custom.0.get(binding) {
-                    Some(custom_action) if action != custom_action => (),
-                    _ => continue,
-                };
-
-                match action_category(action) {
-                    Some(Category::ManageWindow) => self.modified.manage_windows += 1,
-                    Some(Category::MoveWindow) => self.modified.move_windows += 1,
-                    Some(Category::Nav) => self.modified.nav += 1,
-                    Some(Category::System) => self.modified.system += 1,
-                    Some(Category::WindowTiling) => self.modified.window_tiling += 1,
-                    None | Some(Category::Custom) => (),
-                }
-            }
-
-            self.search.defaults = defaults.clone();
-            defaults.0.extend(custom.0);
-            self.search.shortcuts = defaults;
-        }
+        self.reload();
+        self.search_model.load_preferences();
+        self.search_model.watch(sender, |message| {
+            crate::pages::Message::KeyboardShortcuts(Message::SearchShortcut(message))
+        });
 
         Task::none()
     }
@@ -245,26 +371,498 @@ impl Page {
                 }
             },
 
+            Message::ResetAll => {
+                self.reset_dialog = true;
+                Task::none()
+            }
+
+            // Clears every custom shortcut, which also re-enables disabled defaults.
+            Message::ResetAllApply => {
+                self.reset_dialog = false;
+                self.search_model.backup();
+                if let Err(why) = self.search_model.shortcuts_config_set(Shortcuts::default()) {
+                    self.search_model.write_error = Some(why.to_string());
+                }
+
+                self.reload();
+                Task::none()
+            }
+
+            Message::ResetAllCancel => {
+                self.reset_dialog = false;
+                Task::none()
+            }
+
+            Message::RestoreBackup => {
+                if let Some(path) = backup::latest() {
+                    match backup::read(&path) {
+                        Ok(custom) => {
+                            // The shortcuts being replaced are backed up in turn, after the
+                            // backup was read, so that restoring can itself be reverted.
+                            self.search_model.backup();
+
+                            if let Err(why) = self.search_model.shortcuts_config_set(custom) {
+                                self.search_model.write_error = Some(why.to_string());
+                            }
+
+                            self.reload();
+                        }
+
+                        Err(why) => {
+                            tracing::error!(why, ?path, "failed to restore shortcuts backup");
+                        }
+                    }
+                }
+
+                Task::none()
+            }
+
             Message::Search(input) => {
                 self.search(input);
                 Task::none()
             }
 
+            // Counts of modified shortcuts and search results are derived from the config.
+            // The search model lists results rather than the actions of a page, so they are
+            // rebuilt by this page, which keeps the state of the rows still listed.
+            Message::SearchShortcut(ShortcutMessage::ConfigChanged) => {
+                // The search model drops its undo history when the config was changed elsewhere.
+                self.search_model.config_changed();
+                self.reload();
+                Task::none()
+            }
+
+            Message::SearchShortcut(ShortcutMessage::RecoverApply) => {
+                if self.search_model.recover() {
+                    self.reload();
+                }
+
+                Task::none()
+            }
+
+            // Counts of modified shortcuts follow changes which are undone or redone.
+            Message::SearchShortcut(message @ (ShortcutMessage::Undo | ShortcutMessage::Redo)) => {
+                let task = self.search_model.update(message);
+                self.reload();
+                task
+            }
+
             Message::SearchShortcut(message) => self.search_model.update(message),
+
+            #[cfg(feature = "ashpd")]
+            Message::StartExport => cosmic::task::future(async move {
+                let res = SelectedFiles::save_file()
+                    .modal(true)
+                    .current_name(Some("shortcuts.ron"))
+                    .send()
+                    .await
+                    .and_then(|request| request.response());
+
+                match res {
+                    Ok(f) => Message::ExportFile(Arc::new(f)),
+                    Err(ashpd::Error::Response(ResponseError::Cancelled)) => {
+                        Message::FileChooserCancelled
+                    }
+                    Err(why) => Message::ExportError(why.to_string()),
+                }
+            }),
+
+            #[cfg(feature = "ashpd")]
+            Message::ExportFile(f) => {
+                let path_res = f
+                    .uris()
+                    .first()
+                    .filter(|f| f.scheme() == "file")
+                    .and_then(|f| f.to_file_path().ok());
+
+                let Some(path) = path_res else {
+                    return Task::none();
+                };
+
+                let file = export::ShortcutsFile::new(self.search_model.shortcuts_config());
+
+                cosmic::task::future(async move {
+                    let contents = match file.to_ron() {
+                        Ok(contents) => contents,
+                        Err(why) => return Message::ExportError(why.to_string()),
+                    };
+
+                    match tokio::fs::write(path, contents).await {
+                        Ok(()) => Message::ExportSuccess,
+                        Err(why) => Message::ExportError(why.to_string()),
+                    }
+                })
+            }
+
+            #[cfg(feature = "ashpd")]
+            Message::ExportError(why) => {
+                tracing::error!(why, "failed to export shortcuts");
+                self.export_error = Some(why);
+                Task::none()
+            }
+
+            #[cfg(feature = "ashpd")]
+            Message::ExportDismiss => {
+                self.export_error = None;
+                Task::none()
+            }
+
+            #[cfg(feature = "ashpd")]
+            Message::FileChooserCancelled => Task::none(),
+
+            #[cfg(feature = "ashpd")]
+            Message::StartImport => cosmic::task::future(async move {
+                let res = SelectedFiles::open_file()
+                    .modal(true)
+                    .filter(FileFilter::glob(FileFilter::new("ron"), "*.ron"))
+                    .send()
+                    .await
+                    .and_then(|request| request.response());
+
+                match res {
+                    Ok(f) => Message::ImportFile(Arc::new(f)),
+                    Err(ashpd::Error::Response(ResponseError::Cancelled)) => {
+                        Message::FileChooserCancelled
+                    }
+                    Err(why) => Message::ImportError(why.to_string()),
+                }
+            }),
+
+            #[cfg(feature = "ashpd")]
+            Message::ImportFile(f) => {
+                let path_res = f
+                    .uris()
+                    .first()
+                    .filter(|f| f.scheme() == "file")
+                    .and_then(|f| f.to_file_path().ok());
+
+                let Some(path) = path_res else {
+                    return Task::none();
+                };
+
+                cosmic::task::future(async move {
+                    let contents = match tokio::fs::read_to_string(path).await {
+                        Ok(contents) => contents,
+                        Err(why) => return Message::ImportError(why.to_string()),
+                    };
+
+                    match export::ShortcutsFile::from_ron(&contents) {
+                        Ok(file) => Message::ImportLoaded(Arc::new(file.custom), Vec::new()),
+                        Err(why) => Message::ImportError(why),
+                    }
+                })
+            }
+
+            #[cfg(feature = "ashpd")]
+            Message::StartGnomeImport => cosmic::task::future(async move {
+                let res = SelectedFiles::open_file()
+                    .modal(true)
+                    .send()
+                    .await
+                    .and_then(|request| request.response());
+
+                match res {
+                    Ok(f) => Message::ImportGnomeFile(Arc::new(f)),
+                    Err(ashpd::Error::Response(ResponseError::Cancelled)) => {
+                        Message::FileChooserCancelled
+                    }
+                    Err(why) => Message::ImportError(why.to_string()),
+                }
+            }),
+
+            #[cfg(feature = "ashpd")]
+            Message::ImportGnomeFile(f) => {
+                let path_res = f
+                    .uris()
+                    .first()
+                    .filter(|f| f.scheme() == "file")
+                    .and_then(|f| f.to_file_path().ok());
+
+                let Some(path) = path_res else {
+                    return Task::none();
+                };
+
+                cosmic::task::future(async move {
+                    match tokio::fs::read_to_string(path).await {
+                        Ok(contents) => {
+                            let imported = gnome::parse(&contents);
+                            Message::ImportLoaded(Arc::new(imported.shortcuts), imported.skipped)
+                        }
+                        Err(why) => Message::ImportError(why.to_string()),
+                    }
+                })
+            }
+
+            #[cfg(feature = "ashpd")]
+            Message::ImportError(why) => {
+                tracing::error!(why, "failed to import shortcuts");
+                self.import_dialog = Some(ImportDialog::Error(why));
+                Task::none()
+            }
+
+            #[cfg(feature = "ashpd")]
+            Message::ImportLoaded(shortcuts, skipped) => {
+                self.import_dialog = Some(ImportDialog::Choose {
+                    shortcuts: Arc::unwrap_or_clone(shortcuts),
+                    skipped,
+                });
+
+                Task::none()
+            }
+
+            #[cfg(feature = "ashpd")]
+            Message::ImportCancel => {
+                self.import_dialog = None;
+                Task::none()
+            }
+
+            // Adds the imported shortcuts to the current ones, queuing those which conflict.
+            #[cfg(feature = "ashpd")]
+            Message::ImportMerge => {
+                if let Some(ImportDialog::Choose {
+                    shortcuts: imported,
+                    ..
+                }) = self.import_dialog.take()
+                {
+                    let system = self.search_model.shortcuts_system_config();
+                    let mut custom = self.search_model.shortcuts_config();
+                    let mut conflicts = Vec::new();
+
+                    for (binding, action) in imported.0 {
+                        match system.0.get_key_value(&binding) {
+                            Some((_, existing)) if *existing == action => (),
+
+                            Some((existing_binding, existing)) if *existing != Action::Disable => {
+                                let name = localize_custom_action(existing, existing_binding);
+                                conflicts.push((binding, action, name));
+                            }
+
+                            _ => {
+                                custom.0.remove(&binding);
+                                custom.0.insert(binding, action);
+                            }
+                        }
+                    }
+
+                    let previous = self.search_model.shortcuts_config();
+                    self.search_model.backup();
+
+                    if let Err(why) = self.search_model.shortcuts_config_set(custom) {
+                        self.search_model.write_error = Some(why.to_string());
+                    } else {
+                        self.search_model.push_undo(previous);
+                        if !conflicts.is_empty() {
+                            self.import_dialog = Some(ImportDialog::Conflicts(conflicts));
+                        }
+                    }
+
+                    self.reload();
+                }
+
+                Task::none()
+            }
+
+            #[cfg(feature = "ashpd")]
+            Message::ImportReplace => {
+                if let Some(ImportDialog::Choose {
+                    shortcuts: imported,
+                    ..
+                }) = self.import_dialog.take()
+                {
+                    let previous = self.search_model.shortcuts_config();
+                    self.search_model.backup();
+
+                    match self.search_model.shortcuts_config_set(imported) {
+                        Ok(()) => self.search_model.push_undo(previous),
+                        Err(why) => self.search_model.write_error = Some(why.to_string()),
+                    }
+
+                    self.reload();
+                }
+
+                Task::none()
+            }
+
+            #[cfg(feature = "ashpd")]
+            Message::ImportConflictApply => {
+                if let Some(ImportDialog::Conflicts(conflicts)) = self.import_dialog.as_mut() {
+                    if let Some((binding, action, _)) = conflicts.pop() {
+                        if let Err(why) = self.search_model.config_add(action, binding) {
+                            self.search_model.write_error = Some(why.to_string());
+                            conflicts.clear();
+                        }
+                    }
+
+                    if conflicts.is_empty() {
+                        self.import_dialog = None;
+                    }
+
+                    self.reload();
+                }
+
+                Task::none()
+            }
+
+            #[cfg(feature = "ashpd")]
+            Message::ImportConflictSkip => {
+                if let Some(ImportDialog::Conflicts(conflicts)) = self.import_dialog.as_mut() {
+                    _ = conflicts.pop();
+
+                    if conflicts.is_empty() {
+                        self.import_dialog = None;
+                    }
+                }
+
+                Task::none()
+            }
+
+            #[cfg(feature = "ashpd")]
+            Message::ExportSuccess => {
+                tracing::trace!("Export successful");
+                Task::none()
+            }
+        }
+    }
+
+    #[cfg(feature = "ashpd")]
+    fn import_dialog(&self) -> Option<Element<'_, Message>> {
+        let dialog = match self.import_dialog.as_ref()? {
+            ImportDialog::Choose { skipped, .. } => widget::dialog()
+                .title(fl!("shortcuts-import"))
+                .body(if skipped.is_empty() {
+                    fl!("shortcuts-import", "desc")
+                } else {
+                    [
+                        fl!("shortcuts-import", "desc"),
+                        fl!("shortcuts-import", "skipped", list = skipped.join("\n")),
+                    ]
+                    .join("\n\n")
+                })
+                .primary_action(
+                    widget::button::suggested(fl!("shortcuts-import", "merge"))
+                        .on_press(Message::ImportMerge),
+                )
+                .secondary_action(
+                    widget::button::standard(fl!("cancel")).on_press(Message::ImportCancel),
+                )
+                .tertiary_action(
+                    widget::button::destructive(fl!("shortcuts-import", "replace"))
+                        .on_press(Message::ImportReplace),
+                ),
+
+            ImportDialog::Conflicts(conflicts) => {
+                let (binding, _, name) = conflicts.last()?;
+
+                widget::dialog()
+                    .title(fl!("replace-shortcut-dialog"))
+                    .icon(icon::from_name("dialog-warning").size(64))
+                    .body(fl!(
+                        "replace-shortcut-dialog",
+                        "desc",
+                        shortcut = common::binding_label(binding, self.search_model.label),
+                        name = name.clone()
+                    ))
+                    .primary_action(
+                        widget::button::suggested(fl!("replace"))
+                            .on_press(Message::ImportConflictApply),
+                    )
+                    .secondary_action(
+                        widget::button::standard(fl!("shortcuts-import", "skip"))
+                            .on_press(Message::ImportConflictSkip),
+                    )
+            }
+
+            ImportDialog::Error(why) => widget::dialog()
+                .title(fl!("shortcuts-import", "error"))
+                .icon(icon::from_name("dialog-error").size(64))
+                .body(why.as_str())
+                .primary_action(
+                    widget::button::standard(fl!("ok")).on_press(Message::ImportCancel),
+                ),
+        };
+
+        Some(dialog.into())
+    }
+
+    /// Reloads the shortcuts config and recounts the modified shortcuts of each category.
+    fn reload(&mut self) {
+        self.modified = Modified::default();
+        self.has_backup = backup::latest().is_some();
+
+        if let Some(context) = self.shortcuts_context.as_ref() {
+            let mut defaults = context.get::<Shortcuts>("defaults").unwrap_or_default();
+            let custom = self.search_model.load_custom().unwrap_or_default();
+
+            for (custom_binding, custom_action) in &custom.0 {
+                // Skip bindings for the super key
+                if custom_binding.is_super() {
+                    continue;
+                }
+
+                // Check if a custom binding overrides a default binding, or is in addition to it.
+                match defaults.0.get(custom_binding) {
+                    Some(default_action) if default_action == custom_action => continue,
+                    _ => (),
+                }
+
+                match action_category(custom_action) {
+                    Some(Category::ManageWindow) => self.modified.manage_windows += 1,
+                    Some(Category::MoveWindow) => self.modified.move_windows += 1,
+                    Some(Category::Nav) => self.modified.nav += 1,
+                    Some(Category::System) => self.modified.system += 1,
+                    Some(Category::WindowTiling) => self.modified.window_tiling += 1,
+                    None | Some(Category::Custom) => (),
+                }
+            }
+
+            // Check if default bindings are missing
+            for (binding, action) in &defaults.0 {
+                if binding.is_super() {
+                    continue;
+                }
+
+                match custom.0.get(binding) {
+                    Some(custom_action) if action != custom_action => (),
+                    _ => continue,
+                };
+
+                match action_category(action) {
+                    Some(Category::ManageWindow) => self.modified.manage_windows += 1,
+                    Some(Category::MoveWindow) => self.modified.move_windows += 1,
+                    Some(Category::Nav) => self.modified.nav += 1,
+                    Some(Category::System) => self.modified.system += 1,
+                    Some(Category::WindowTiling) => self.modified.window_tiling += 1,
+                    None | Some(Category::Custom) => (),
+                }
+            }
+
+            self.search.defaults = defaults.clone();
+            self.search_model.defaults = defaults.clone();
+            defaults.0.extend(custom.0);
+            self.search.shortcuts = defaults;
+        }
+
+        // Refresh the search results, which may include custom shortcuts.
+        if !self.search.input.is_empty() {
+            self.search.actions.clear();
+            let input = std::mem::take(&mut self.search.input);
+            self.search(input);
         }
     }
 
     fn search(&mut self, input: String) {
         self.search.input = input;
         if self.search.input.is_empty() {
-            self.search_model.on_clear();
+            self.search_model.clear_results();
             return;
         }
         if self.search.actions.is_empty() {
             self.search.cache_localized_actions();
         }
 
-        self.search_model.shortcut_models = self.search.shortcut_models();
+        // Edits in progress and the shortcut in context are kept while the results change.
+        let models = self.search.shortcut_models();
+        self.search_model.replace_models(models);
     }
 }
 
@@ -414,11 +1012,22 @@ fn shortcuts() -> Section<crate::pages::Message> {
                     ))
                     .apply(Element::from)
             } else {
-                page.search_model.view().map(Message::SearchShortcut)
+                widget::column::with_capacity(2)
+                    .spacing(24)
+                    .push(page.search_model.filter_toggles())
+                    .push(page.search_model.view())
+                    .apply(Element::from)
+                    .map(Message::SearchShortcut)
             };
 
-            widget::column::with_capacity(2)
+            let read_warning = page
+                .search_model
+                .read_warning()
+                .map(|el| el.map(Message::SearchShortcut));
+
+            widget::column::with_capacity(3)
                 .spacing(32)
+                .push_maybe(read_warning)
                 .push(search)
                 .push(content)
                 .apply(Element::from)
@@ -426,6 +1035,27 @@ fn shortcuts() -> Section<crate::pages::Message> {
         })
 }
 
+/// Preferences for how bindings are displayed on every shortcuts page.
+fn display() -> Section<crate::pages::Message> {
+    let mut descriptions = Slab::new();
+
+    // Make these searchable in the global settings search.
+    descriptions.insert(fl!("shortcut-symbols"));
+    descriptions.insert(fl!("shortcut-logo-meta"));
+    descriptions.insert(fl!("shortcut-modifier-order"));
+    descriptions.insert(fl!("shortcut-compare"));
+
+    Section::default()
+        .title(fl!("shortcut-display"))
+        .descriptions(descriptions)
+        .view::<Page>(move |_binder, page, section| {
+            page.search_model
+                .display_settings(&section.title)
+                .map(Message::SearchShortcut)
+                .map(crate::pages::Message::KeyboardShortcuts)
+        })
+}
+
 /// Display a category as a list item
 fn category_item(category: Category, name: &str, modified: u16) -> Element<Message> {
     let icon = icon::from_name("go-next-symbolic").size(16);
@@ -1,5 +1,6 @@
 use super::{ShortcutMessage, ShortcutModel};
-use cosmic::{Element, Task};
+use cosmic::widget;
+use cosmic::{Apply, Element, Task};
 use cosmic_settings_config::shortcuts::action::Direction;
 use cosmic_settings_config::shortcuts::Action;
 use cosmic_settings_page::{self as page, section, Section};
@@ -58,9 +59,11 @@ impl page::Page<crate::pages::Message> for Page {
 
     fn on_enter(
         &mut self,
-        _sender: tokio::sync::mpsc::Sender<crate::pages::Message>,
+        sender: tokio::sync::mpsc::Sender<crate::pages::Message>,
     ) -> Task<crate::pages::Message> {
         self.model.on_enter();
+        self.model
+            .watch(sender, crate::pages::Message::MoveWindowShortcuts);
 
         Task::none()
     }
@@ -112,8 +115,13 @@ fn shortcuts() -> Section<crate::pages::Message> {
     Section::default()
         .descriptions(descriptions)
         .view::<Page>(move |_binder, page, _section| {
-            page.model
-                .view()
+            widget::column::with_capacity(4)
+                .spacing(32)
+                .push_maybe(page.model.read_warning())
+                .push(page.model.filters())
+                .push(page.model.view())
+                .push_maybe(page.model.reset_category_button())
+                .apply(Element::from)
                 .map(crate::pages::Message::MoveWindowShortcuts)
         })
 }
@@ -0,0 +1,104 @@
+//! Writes of the custom shortcuts, made off the update thread.
+
+use super::ShortcutMessage;
+use cosmic_config::ConfigSet;
+use cosmic_settings_config::shortcuts::{self, Shortcuts};
+use once_cell::sync::Lazy;
+use std::sync::{mpsc, Arc, Mutex};
+
+/// Page which failed writes are reported to, and how its messages are wrapped.
+type Reporter = (
+    tokio::sync::mpsc::Sender<crate::pages::Message>,
+    fn(ShortcutMessage) -> crate::pages::Message,
+);
+
+/// Writer shared by every shortcuts page, so that the writes of all pages are made in the order
+/// they were queued, and no page reads the config while a write of another is still pending.
+static SHARED: Lazy<Arc<Writer>> = Lazy::new(|| {
+    let (queue, writes) = mpsc::channel();
+    let writer = Arc::new(Writer::new(queue));
+
+    let thread_writer = writer.clone();
+    std::thread::spawn(move || thread_writer.run(writes));
+
+    writer
+});
+
+/// Queue of writes to the custom shortcuts config.
+pub struct Writer {
+    queue: mpsc::Sender<Shortcuts>,
+    /// Number of queued writes which have not yet been written, and the last of them.
+    pending: Mutex<(usize, Shortcuts)>,
+    reporter: Mutex<Option<Reporter>>,
+}
+
+impl Writer {
+    /// A writer which sends writes to the queue, where they are made by its receiver.
+    pub fn new(queue: mpsc::Sender<Shortcuts>) -> Self {
+        Self {
+            queue,
+            pending: Mutex::new((0, Shortcuts::default())),
+            reporter: Mutex::new(None),
+        }
+    }
+
+    /// The writer shared by every shortcuts page.
+    pub fn shared() -> Arc<Self> {
+        SHARED.clone()
+    }
+
+    /// Queues a write, giving back the shortcuts if they can no longer be queued.
+    pub fn queue(&self, shortcuts: Shortcuts) -> Result<(), Shortcuts> {
+        let mut pending = self.pending.lock().unwrap();
+
+        match self.queue.send(shortcuts.clone()) {
+            Ok(()) => {
+                pending.0 += 1;
+                pending.1 = shortcuts;
+                Ok(())
+            }
+            Err(mpsc::SendError(shortcuts)) => Err(shortcuts),
+        }
+    }
+
+    /// The last queued shortcuts, while the config is outdated by writes which are still pending.
+    pub fn pending(&self) -> Option<Shortcuts> {
+        let pending = self.pending.lock().unwrap();
+        (pending.0 > 0).then(|| pending.1.clone())
+    }
+
+    /// Reports failed writes to the page as [`ShortcutMessage::WriteFailed`].
+    pub fn report_to(
+        &self,
+        sender: tokio::sync::mpsc::Sender<crate::pages::Message>,
+        message_map: fn(ShortcutMessage) -> crate::pages::Message,
+    ) {
+        *self.reporter.lock().unwrap() = Some((sender, message_map));
+    }
+
+    /// Makes the queued writes in order, which continues after the page is left.
+    fn run(&self, writes: mpsc::Receiver<Shortcuts>) {
+        let config = match shortcuts::context() {
+            Ok(config) => config,
+            Err(why) => {
+                tracing::error!(?why, "unable to open the shortcuts config for writing");
+                return;
+            }
+        };
+
+        for shortcuts in writes {
+            let result = config.set("custom", shortcuts);
+            self.pending.lock().unwrap().0 -= 1;
+
+            if let Err(why) = result {
+                tracing::error!(?why, "failed to write shortcuts config");
+
+                let reporter = self.reporter.lock().unwrap().clone();
+                if let Some((sender, message_map)) = reporter {
+                    let message = ShortcutMessage::WriteFailed(why.to_string());
+                    _ = sender.blocking_send(message_map(message));
+                }
+            }
+        }
+    }
+}
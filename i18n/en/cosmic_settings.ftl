@@ -531,11 +531,13 @@ keyboard-shortcuts = Keyboard Shortcuts
     .desc = View and customize shortcuts
 
 add-keybinding = Add keybinding
+disable-all-bindings = Disable all
 cancel = Cancel
 command = Command
 custom = Custom
 debug = Debug
 disabled = Disabled
+not-set = Not set
 migrate-workspace-prev = Migrate workspace to previous output
 migrate-workspace-next = Migrate workspace to next output
 migrate-workspace = Migrate workspace to output { $direction ->
@@ -559,6 +561,93 @@ custom-shortcuts = Custom Shortcuts
 
 modified = { $count } modified
 
+shortcut-filter = Filter
+    .modified = Show only modified shortcuts
+    .custom = Show only custom shortcuts
+    .modifiers = Bound with modifiers
+
+shortcut-lookup = Find by key combination
+    .invalid = Not a valid key combination
+    .elsewhere = Used by { $name }
+    .none = No shortcut uses this key combination
+
+shortcut-bindings = Bindings
+    .more = +{ $count } more
+    .show-less = Show less
+
+shortcut-display = Display
+shortcut-symbols = Show modifiers as symbols
+shortcut-logo-meta = Name the logo key Meta
+shortcut-modifier-order = Modifier order
+    .move = Move earlier
+
+shortcut-compare = Compare with defaults
+    .default = Default
+    .current = Current
+
+modifier-key =
+    .super = Super
+    .meta = Meta
+    .ctrl = Ctrl
+    .alt = Alt
+    .shift = Shift
+
+key-name =
+    .backspace = Backspace
+    .brightness-down = Brightness Down
+    .brightness-up = Brightness Up
+    .caps-lock = Caps Lock
+    .delete = Delete
+    .down = Down
+    .end = End
+    .escape = Esc
+    .home = Home
+    .insert = Insert
+    .left = Left
+    .media-next = Next Track
+    .media-play = Play
+    .media-previous = Previous Track
+    .menu = Menu
+    .mute = Mute
+    .numpad-enter = Numpad Enter
+    .page-down = Page Down
+    .page-up = Page Up
+    .print = Print Screen
+    .return = Enter
+    .right = Right
+    .space = Space
+    .tab = Tab
+    .up = Up
+    .volume-down = Volume Down
+    .volume-up = Volume Up
+
+default-binding = Default
+    .disabled = Disabled
+
+shortcut-button =
+    .clear = Revert input
+    .copy = Copy binding
+    .delete-binding = Delete binding
+    .delete-shortcut = Delete shortcut
+    .duplicate = Duplicate shortcut
+    .reset = Reset to default binding
+    .suggest = Pick a free binding
+
+undo = Undo
+redo = Redo
+
+command-not-found = { $executable } was not found, so this shortcut may not work.
+
+shortcuts-write-error = Shortcuts Not Saved
+    .desc = The change could not be written to the shortcuts config: { $why }
+
+shortcuts-read-error = Custom shortcuts could not be read, so only the defaults are shown: { $why }
+
+shortcuts-recover = Recover
+    .dialog = Custom Shortcuts Unreadable
+    .desc = The custom shortcuts config could not be read ({ $why }). Move it to the shortcut backups and start with no custom shortcuts? It is also moved there before a shortcut is changed.
+    .apply = Move and start fresh
+
 nav-shortcuts = Navigation
     .prev-output = Focus previous output
     .next-output = Focus next output
@@ -652,6 +741,40 @@ window-tiling = Window tiling
 
 replace-shortcut-dialog = Replace Shortcut?
     .desc = { $shortcut } is used by { $name }. If you replace it, { $name } will be disabled.
+    .suggestion = Use { $shortcut }
+
+delete-shortcut-dialog = Delete Shortcut?
+    .desc = All { $count } key combinations of { $name } will be removed.
+    .apply = Delete
+
+reserved-shortcut-warning = This shortcut may be intercepted by the system.
+shadowed-binding-warning = Differs only by modifiers from { $binding }, used by { $name }.
+invalid-binding = Not a valid key combination.
+binding-limit = A shortcut can have at most { $count } bindings.
+
+reset-all-shortcuts = Reset all shortcuts
+    .dialog = Reset All Shortcuts?
+    .desc = All custom shortcuts will be removed, and all disabled shortcuts will be restored to their defaults.
+
+reset-category-shortcuts = Reset these shortcuts
+    .dialog = Reset These Shortcuts?
+    .desc = Every shortcut on this page will be restored to its defaults. Custom bindings of these shortcuts will be removed.
+
+restore-shortcuts-backup = Restore backup
+
+shortcuts-import = Import Shortcuts
+    .desc = Merge the imported shortcuts with your current shortcuts, or replace your custom shortcuts with them?
+    .merge = Merge
+    .replace = Replace all
+    .skip = Skip
+    .gnome = Import from GNOME
+    .skipped = These keybindings could not be imported:
+        { $list }
+    .error = Unable to import shortcuts
+    .unsupported = This file was exported by a newer version of COSMIC Settings.
+
+shortcuts-export = Export Shortcuts
+    .error = Unable to export shortcuts
 
 ## Input: Mouse
 